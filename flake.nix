@@ -87,6 +87,10 @@
           };
         };
         features = {
+          alloc = {
+            dependencies = { };
+            other-features = [ ];
+          };
           malachite = {
             dependencies = {
               malachite-base = {
@@ -113,7 +117,7 @@
           };
           std = {
             dependencies = { };
-            other-features = [ ];
+            other-features = [ "alloc" ];
           };
         };
         feature-dependencies = builtins.foldl' (
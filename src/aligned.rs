@@ -0,0 +1,51 @@
+//! Integers that are multiples of a power-of-two alignment (e.g. addresses or offsets).
+
+use {
+    crate::{Bits, Sigma, Test},
+    core::{fmt, marker::PhantomData},
+};
+
+/// Integers that are multiples of a power-of-two alignment (e.g. addresses or offsets).
+pub type Aligned<Input, const ALIGN: usize> = Sigma<Input, AlignedInvariant<Input, ALIGN>>;
+
+/// Integers that are multiples of a power-of-two alignment (e.g. addresses or offsets).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AlignedInvariant<Input: Bits + fmt::Debug, const ALIGN: usize>(PhantomData<Input>);
+
+impl<Input: Bits + fmt::Debug, const ALIGN: usize> Test<Input, 1>
+    for AlignedInvariant<Input, ALIGN>
+{
+    const ADJECTIVE: &str = "aligned";
+
+    type Error<'i>
+        = NotAligned<'i, Input, ALIGN>
+    where
+        Input: 'i;
+
+    #[inline(always)]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        const { assert!(ALIGN.is_power_of_two(), "alignment must be a power of two") };
+        if input.trailing_zeros() >= ALIGN.trailing_zeros() {
+            Ok(())
+        } else {
+            Err(NotAligned(input))
+        }
+    }
+}
+
+/// A term expected to be aligned was not.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotAligned<'i, Input: Bits + fmt::Debug, const ALIGN: usize>(&'i Input);
+
+impl<Input: Bits + fmt::Debug, const ALIGN: usize> fmt::Display for NotAligned<'_, Input, ALIGN> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self(z) = *self;
+        write!(f, "{z:#?} is not a multiple of {ALIGN}")
+    }
+}
@@ -0,0 +1,222 @@
+//! Angles in radians or degrees, normalized to one full turn
+//! (either `[0, 2π)` or, if signed, `(-π, π]`).
+//!
+//! Arithmetic wraps back onto the same range instead of panicking.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{
+        Const, ConstInt, ConstOne, ConstZero, Finite, Interval, MinusOne, MinusPi, Pi, Tau,
+        Zero as _,
+    },
+    core::ops,
+};
+
+/// Angles in degrees on `[0, 360)`.
+pub type Degrees<Input> = Interval<Input, ConstZero, ConstInt<360>, true, false>;
+
+/// Terms on `[-1, 1]` (e.g. sines and cosines).
+pub type OnSignedUnit<Input> = Interval<Input, MinusOne, ConstOne, true, true>;
+
+/// Angles in radians on `[0, 2π)`.
+pub type Radians<Input> = Interval<Input, ConstZero, Tau, true, false>;
+
+/// Angles in degrees on `(-180, 180]`.
+pub type SignedDegrees<Input> = Interval<Input, ConstInt<{ -180 }>, ConstInt<180>, false, true>;
+
+/// Angles in radians on `(-π, π]`.
+pub type SignedRadians<Input> = Interval<Input, MinusPi, Pi, false, true>;
+
+/// Normalize an angle onto one full turn and implement arithmetic that wraps around.
+macro_rules! impl_wrap {
+    ($f:ident, $angle:ident, $turn:ty, unsigned) => {
+        impl $angle<$f> {
+            /// Wrap any finite angle onto `[0, 1)` turns.
+            #[inline]
+            #[must_use]
+            pub fn normalize(angle: Finite<$f>) -> Self {
+                let turn = <$turn as Const<$f>>::VALUE;
+                let rem = ops::Rem::rem(angle.get(), turn);
+                Self::new(if rem < $f::ZERO {
+                    // Adding a full turn to a tiny negative remainder can round up to a full turn:
+                    let wrapped = rem + turn;
+                    if wrapped < turn { wrapped } else { $f::ZERO }
+                } else {
+                    rem
+                })
+            }
+        }
+
+        impl_wrap!($f, $angle);
+    };
+    ($f:ident, $angle:ident, $turn:ty, $half_turn:ty) => {
+        impl $angle<$f> {
+            /// Wrap any finite angle onto `(-1/2, 1/2]` turns.
+            #[inline]
+            #[must_use]
+            pub fn normalize(angle: Finite<$f>) -> Self {
+                let turn = <$turn as Const<$f>>::VALUE;
+                let half_turn = <$half_turn as Const<$f>>::VALUE;
+                // Each adjustment is exact, since both operands are within a factor of two:
+                let rem = ops::Rem::rem(angle.get(), turn);
+                Self::new(if rem > half_turn {
+                    rem - turn
+                } else if rem <= -half_turn {
+                    rem + turn
+                } else {
+                    rem
+                })
+            }
+        }
+
+        impl_wrap!($f, $angle);
+    };
+    ($f:ident, $angle:ident) => {
+        impl ops::Add for $angle<$f> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::normalize(Finite::new(self.get() + rhs.get()))
+            }
+        }
+
+        impl ops::AddAssign for $angle<$f> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = ops::Add::add(*self, rhs);
+            }
+        }
+
+        impl ops::Neg for $angle<$f> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::normalize(Finite::new(-self.get()))
+            }
+        }
+
+        impl ops::Sub for $angle<$f> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::normalize(Finite::new(self.get() - rhs.get()))
+            }
+        }
+
+        impl ops::SubAssign for $angle<$f> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = ops::Sub::sub(*self, rhs);
+            }
+        }
+    };
+}
+
+/// Convert between units and between signed and unsigned ranges.
+macro_rules! impl_convert {
+    ($f:ident, $radians:ident, $degrees:ident, $other_radians:ident, $other_degrees:ident, $to_other:ident, $other:literal) => {
+        impl $degrees<$f> {
+            /// Convert to radians on the corresponding range.
+            #[inline]
+            #[must_use]
+            pub fn to_radians(self) -> $radians<$f> {
+                $radians::<$f>::normalize(Finite::new(self.get().to_radians()))
+            }
+
+            #[doc = concat!("Convert to ", $other, " degrees.")]
+            #[inline]
+            #[must_use]
+            pub fn $to_other(self) -> $other_degrees<$f> {
+                $other_degrees::<$f>::normalize(Finite::new(self.get()))
+            }
+        }
+
+        impl $radians<$f> {
+            /// Convert to degrees on the corresponding range.
+            #[inline]
+            #[must_use]
+            pub fn to_degrees(self) -> $degrees<$f> {
+                $degrees::<$f>::normalize(Finite::new(self.get().to_degrees()))
+            }
+
+            #[doc = concat!("Convert to ", $other, " radians.")]
+            #[inline]
+            #[must_use]
+            pub fn $to_other(self) -> $other_radians<$f> {
+                $other_radians::<$f>::normalize(Finite::new(self.get()))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl $degrees<$f> {
+            /// Cosine.
+            #[inline]
+            #[must_use]
+            pub fn cos(self) -> OnSignedUnit<$f> {
+                self.to_radians().cos()
+            }
+
+            /// Sine.
+            #[inline]
+            #[must_use]
+            pub fn sin(self) -> OnSignedUnit<$f> {
+                self.to_radians().sin()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl $radians<$f> {
+            /// Cosine.
+            #[inline]
+            #[must_use]
+            pub fn cos(self) -> OnSignedUnit<$f> {
+                self.map($f::cos)
+            }
+
+            /// Sine.
+            #[inline]
+            #[must_use]
+            pub fn sin(self) -> OnSignedUnit<$f> {
+                self.map($f::sin)
+            }
+        }
+    };
+}
+
+/// Implement each angle type for a primitive floating-point type.
+macro_rules! impl_angle {
+    ($f:ident) => {
+        impl_wrap!($f, Degrees, ConstInt<360>, unsigned);
+        impl_wrap!($f, Radians, Tau, unsigned);
+        impl_wrap!($f, SignedDegrees, ConstInt<360>, ConstInt<180>);
+        impl_wrap!($f, SignedRadians, Tau, Pi);
+        impl_convert!(
+            $f,
+            Radians,
+            Degrees,
+            SignedRadians,
+            SignedDegrees,
+            to_signed,
+            "signed"
+        );
+        impl_convert!(
+            $f,
+            SignedRadians,
+            SignedDegrees,
+            Radians,
+            Degrees,
+            to_unsigned,
+            "unsigned"
+        );
+    };
+}
+
+impl_angle!(f32);
+impl_angle!(f64);
@@ -0,0 +1,67 @@
+//! Iterable data structure in which at least one element satisfies a given invariant.
+
+use core::{fmt, marker::PhantomData};
+
+/// Iterable data structure in which at least one element satisfies a given invariant.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Any<Invariant: crate::Test<Input::Item, 1>, Input: IntoIterator + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+)
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>;
+
+impl<Invariant: crate::Test<Input::Item, 1>, Input: IntoIterator + fmt::Debug> crate::Test<Input, 1>
+    for Any<Invariant, Input>
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    const ADJECTIVE: &str = "valid somewhere";
+
+    type Error<'i>
+        = NotAny<Input::Item, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let mut checked: usize = 0;
+        for element in input {
+            if Invariant::test([element]).is_ok() {
+                return Ok(());
+            }
+            checked = checked.saturating_add(1);
+        }
+        Err(NotAny {
+            checked,
+            phantom_invariant: PhantomData,
+            phantom_item: PhantomData,
+        })
+    }
+}
+
+/// No element in an iterator satisfied the given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotAny<Item: fmt::Debug, Invariant: crate::Test<Item, 1>> {
+    /// How many elements were checked (and failed).
+    checked: usize,
+    /// Only to silence compiler errors.
+    phantom_invariant: PhantomData<Invariant>,
+    /// Only to silence compiler errors.
+    phantom_item: PhantomData<Item>,
+}
+
+impl<Item: fmt::Debug, Invariant: crate::Test<Item, 1>> fmt::Display for NotAny<Item, Invariant> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { checked, .. } = *self;
+        write!(
+            f,
+            "None of {checked} element(s) were {}",
+            Invariant::ADJECTIVE,
+        )
+    }
+}
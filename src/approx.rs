@@ -0,0 +1,143 @@
+//! Terms equal to a constant or on the unit interval
+//! up to a given tolerance (e.g. for accumulated floating-point rounding error).
+
+use {
+    crate::{Const, One, Sigma, Test, Zero},
+    core::{fmt, marker::PhantomData, ops},
+};
+
+/// Terms within a given tolerance of a constant.
+pub type ApproxEq<Input, Target, Tolerance> =
+    Sigma<Input, ApproxEqInvariant<Input, Target, Tolerance>>;
+
+/// Terms within a given tolerance of a constant.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ApproxEqInvariant<
+    Input: PartialOrd + fmt::Debug + ops::Add<Output = Input> + ops::Sub<Output = Input>,
+    Target: Const<Input>,
+    Tolerance: Const<Input>,
+>(
+    PhantomData<Input>,
+    PhantomData<Target>,
+    PhantomData<Tolerance>,
+);
+
+impl<
+    Input: PartialOrd + fmt::Debug + ops::Add<Output = Input> + ops::Sub<Output = Input>,
+    Target: Const<Input>,
+    Tolerance: Const<Input>,
+> Test<Input, 1> for ApproxEqInvariant<Input, Target, Tolerance>
+{
+    const ADJECTIVE: &str = "approximately equal";
+
+    type Error<'i>
+        = NotApproxEq<'i, Input>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if *input >= Target::VALUE.sub(Tolerance::VALUE)
+            && *input <= Target::VALUE.add(Tolerance::VALUE)
+        {
+            Ok(())
+        } else {
+            Err(NotApproxEq {
+                input,
+                target: Target::VALUE,
+                tolerance: Tolerance::VALUE,
+            })
+        }
+    }
+}
+
+/// Terms on the unit interval (between 0 and 1) up to a given tolerance at each extreme.
+pub type ApproxOnUnit<Input, Tolerance> = Sigma<Input, ApproxOnUnitInvariant<Input, Tolerance>>;
+
+/// Terms on the unit interval (between 0 and 1) up to a given tolerance at each extreme.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ApproxOnUnitInvariant<
+    Input: One + PartialOrd + Zero + fmt::Debug + ops::Add<Output = Input> + ops::Sub<Output = Input>,
+    Tolerance: Const<Input>,
+>(PhantomData<Input>, PhantomData<Tolerance>);
+
+impl<
+    Input: One + PartialOrd + Zero + fmt::Debug + ops::Add<Output = Input> + ops::Sub<Output = Input>,
+    Tolerance: Const<Input>,
+> Test<Input, 1> for ApproxOnUnitInvariant<Input, Tolerance>
+{
+    const ADJECTIVE: &str = "approximately on the unit interval";
+
+    type Error<'i>
+        = NotApproxOnUnit<'i, Input>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if *input >= Input::ZERO.sub(Tolerance::VALUE) && *input <= Input::ONE.add(Tolerance::VALUE)
+        {
+            Ok(())
+        } else {
+            Err(NotApproxOnUnit {
+                input,
+                tolerance: Tolerance::VALUE,
+            })
+        }
+    }
+}
+
+/// A term expected to be within a given tolerance of a constant was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotApproxEq<'i, Input: fmt::Debug> {
+    /// Offending term.
+    input: &'i Input,
+    /// Value it should have been near.
+    target: Input,
+    /// How near it should have been.
+    tolerance: Input,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotApproxEq<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            input,
+            ref target,
+            ref tolerance,
+        } = *self;
+        write!(f, "{input:#?} is not within {tolerance:#?} of {target:#?}")
+    }
+}
+
+/// A term expected to be on the unit interval (up to a given tolerance) was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotApproxOnUnit<'i, Input: fmt::Debug> {
+    /// Offending term.
+    input: &'i Input,
+    /// How far outside the unit interval it could have been.
+    tolerance: Input,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotApproxOnUnit<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            input,
+            ref tolerance,
+        } = *self;
+        write!(f, "{input:#?} is not within {tolerance:#?} of [0, 1]")
+    }
+}
@@ -0,0 +1,150 @@
+//! Arithmetic that reports or saturates on overflow instead of wrapping.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+/// Adds two numbers, returning `None` if the result is not representable.
+pub trait CheckedAdd<Rhs = Self> {
+    /// Type of the sum.
+    type Output;
+
+    /// Add two numbers, returning `None` if the result is not representable.
+    fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Divides two numbers, returning `None` if the result is not representable.
+pub trait CheckedDiv<Rhs = Self> {
+    /// Type of the quotient.
+    type Output;
+
+    /// Divide two numbers, returning `None` if the result is not representable.
+    fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Multiplies two numbers, returning `None` if the result is not representable.
+pub trait CheckedMul<Rhs = Self> {
+    /// Type of the product.
+    type Output;
+
+    /// Multiply two numbers, returning `None` if the result is not representable.
+    fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Subtracts two numbers, returning `None` if the result is not representable.
+pub trait CheckedSub<Rhs = Self> {
+    /// Type of the difference.
+    type Output;
+
+    /// Subtract two numbers, returning `None` if the result is not representable.
+    fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Adds two numbers, saturating at the numeric bounds instead of overflowing.
+pub trait SaturatingAdd<Rhs = Self> {
+    /// Type of the sum.
+    type Output;
+
+    /// Add two numbers, saturating at the numeric bounds instead of overflowing.
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Multiplies two numbers, saturating at the numeric bounds instead of overflowing.
+pub trait SaturatingMul<Rhs = Self> {
+    /// Type of the product.
+    type Output;
+
+    /// Multiply two numbers, saturating at the numeric bounds instead of overflowing.
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Subtracts two numbers, saturating at the numeric bounds instead of overflowing.
+pub trait SaturatingSub<Rhs = Self> {
+    /// Type of the difference.
+    type Output;
+
+    /// Subtract two numbers, saturating at the numeric bounds instead of overflowing.
+    fn saturating_sub(self, rhs: Rhs) -> Self::Output;
+}
+
+/// Forward each trait to the primitive's inherent method.
+macro_rules! impl_primitive {
+    ($t:ident) => {
+        impl CheckedAdd for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                $t::checked_add(self, rhs)
+            }
+        }
+
+        impl CheckedDiv for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn checked_div(self, rhs: Self) -> Option<Self> {
+                $t::checked_div(self, rhs)
+            }
+        }
+
+        impl CheckedMul for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                $t::checked_mul(self, rhs)
+            }
+        }
+
+        impl CheckedSub for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                $t::checked_sub(self, rhs)
+            }
+        }
+
+        impl SaturatingAdd for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn saturating_add(self, rhs: Self) -> Self {
+                $t::saturating_add(self, rhs)
+            }
+        }
+
+        impl SaturatingMul for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn saturating_mul(self, rhs: Self) -> Self {
+                $t::saturating_mul(self, rhs)
+            }
+        }
+
+        impl SaturatingSub for $t {
+            type Output = Self;
+
+            #[inline(always)]
+            fn saturating_sub(self, rhs: Self) -> Self {
+                $t::saturating_sub(self, rhs)
+            }
+        }
+    };
+}
+
+impl_primitive!(i8);
+impl_primitive!(i16);
+impl_primitive!(i32);
+impl_primitive!(i64);
+impl_primitive!(i128);
+impl_primitive!(isize);
+impl_primitive!(u8);
+impl_primitive!(u16);
+impl_primitive!(u32);
+impl_primitive!(u64);
+impl_primitive!(u128);
+impl_primitive!(usize);
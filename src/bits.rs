@@ -0,0 +1,87 @@
+//! Integer types whose binary representation can be inspected.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use crate::{Even, Odd, Positive, PowerOfTwo};
+
+/// Integer types whose binary representation can be inspected.
+pub trait Bits {
+    /// Check that this value is a (positive) power of two.
+    fn check_power_of_two(&self) -> bool;
+
+    /// Number of trailing zeros in the binary representation of this value
+    /// (or the number of bits in this type if this value is zero).
+    fn trailing_zeros(&self) -> u32;
+}
+
+/// Implement `Bits` and bounded bit-level methods for a primitive integer type.
+macro_rules! impl_bits {
+    ($t:ident) => {
+        impl Bits for $t {
+            #[inline(always)]
+            fn check_power_of_two(&self) -> bool {
+                // Excludes the minimum value of signed types,
+                // which also has exactly one bit set:
+                *self > 0 && self.count_ones() == 1
+            }
+
+            #[inline(always)]
+            fn trailing_zeros(&self) -> u32 {
+                $t::trailing_zeros(*self)
+            }
+        }
+
+        impl Even<$t> {
+            /// Number of trailing zeros in the binary representation of this value,
+            /// which is at least one.
+            #[inline]
+            #[must_use]
+            pub fn trailing_zeros(self) -> Positive<u32> {
+                self.map($t::trailing_zeros)
+            }
+        }
+
+        impl Odd<$t> {
+            /// Number of trailing zeros in the binary representation of this value,
+            /// which is always zero.
+            #[inline]
+            #[must_use]
+            pub fn trailing_zeros(self) -> crate::u32::EqualTo<0> {
+                self.map($t::trailing_zeros)
+            }
+        }
+
+        impl PowerOfTwo<$t> {
+            /// Base-2 logarithm, which is exact and less than the number of bits in this type.
+            #[inline]
+            #[must_use]
+            pub fn ilog2(self) -> crate::u32::LessThan<{ $t::BITS }> {
+                self.map($t::ilog2)
+            }
+
+            /// Number of trailing zeros in the binary representation of this value,
+            /// which is equal to its base-2 logarithm.
+            #[inline]
+            #[must_use]
+            pub fn trailing_zeros(self) -> crate::u32::LessThan<{ $t::BITS }> {
+                self.map($t::trailing_zeros)
+            }
+        }
+    };
+}
+
+impl_bits!(i8);
+impl_bits!(i16);
+impl_bits!(i32);
+impl_bits!(i64);
+impl_bits!(i128);
+impl_bits!(isize);
+impl_bits!(u8);
+impl_bits!(u16);
+impl_bits!(u32);
+impl_bits!(u64);
+impl_bits!(u128);
+impl_bits!(usize);
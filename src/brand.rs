@@ -0,0 +1,146 @@
+//! Indices branded with a unique lifetime tied to one particular slice,
+//! so they're checked once, when created, and never again in release builds.
+//!
+//! Each call to `brand` introduces a fresh lifetime that can't be named
+//! or unified with any other, so an index from one slice
+//! fails to compile if used with another (the "generativity" trick).
+//!
+//! A branded index checks the same invariant as `InBounds`
+//! (and is created only via `InBounds::try_new_with`),
+//! but it can't simply wrap an `InBounds` (or any other `Sigma` or `SigmaWith`):
+//! those trust any value passed to `new` or `new_with` in release builds,
+//! and since `Branded::get` skips the bounds check entirely,
+//! an index that could be constructed without checking would be unsound.
+//! Instead, branded indices can be constructed only inside this module, and only by checking them.
+
+use {
+    crate::InBounds,
+    core::{fmt, marker::PhantomData},
+};
+
+/// Lifetime that can be neither lengthened nor shortened,
+/// so two brands are compatible only if they're identical.
+type Brand<'id> = PhantomData<fn(&'id ()) -> &'id ()>;
+
+/// Slice branded with a unique lifetime, shared with each index checked against it.
+pub struct Branded<'id, 's, T> {
+    /// Only to tie this slice to its indices.
+    brand: Brand<'id>,
+    /// Slice whose length every index with this brand is less than.
+    slice: &'s [T],
+}
+
+impl<'id, 's, T> Branded<'id, 's, T> {
+    /// Element at a branded index, without a bounds check in release builds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: BrandedIndex<'id>) -> &'s T {
+        debug_assert!(
+            index.raw < self.slice.len(),
+            "branded index {} out of bounds for length {}",
+            index.raw,
+            self.slice.len(),
+        );
+        // SAFETY:
+        // Indices with this brand are created only by checking against this slice,
+        // which can't have changed length, since we hold a shared reference to it.
+        unsafe { self.slice.get_unchecked(index.raw) }
+    }
+
+    /// Check an index against this slice, and if it's in bounds,
+    /// brand it so it's never checked again.
+    #[inline]
+    #[must_use]
+    pub fn index(&self, index: usize) -> Option<BrandedIndex<'id>> {
+        InBounds::try_new_with(index, self.slice)
+            .ok()
+            .map(|in_bounds| BrandedIndex {
+                brand: PhantomData,
+                raw: in_bounds.get(),
+            })
+    }
+
+    /// Every index in bounds for this slice, in order, already branded.
+    #[inline]
+    pub fn indices(&self) -> impl Iterator<Item = BrandedIndex<'id>> {
+        (0..self.slice.len()).map(|raw| BrandedIndex {
+            brand: PhantomData,
+            raw,
+        })
+    }
+
+    /// Unwrap the slice.
+    #[inline(always)]
+    #[must_use]
+    pub const fn slice(&self) -> &'s [T] {
+        self.slice
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Branded<'_, '_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.slice, f)
+    }
+}
+
+/// Index in bounds for the one slice with the same brand.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BrandedIndex<'id> {
+    /// Only to tie this index to its slice.
+    brand: Brand<'id>,
+    /// Internal index, already checked against the slice with the same brand.
+    raw: usize,
+}
+
+impl BrandedIndex<'_> {
+    /// Unwrap the internal index.
+    #[inline(always)]
+    #[must_use]
+    pub const fn get(self) -> usize {
+        self.raw
+    }
+}
+
+impl fmt::Debug for BrandedIndex<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.raw, f)
+    }
+}
+
+/// Brand a slice with a fresh lifetime for the duration of a closure,
+/// within which its indices can be checked once and then used without checking.
+///
+/// ```
+/// use sigma_types::brand;
+///
+/// let sum = brand(&[1, 2, 3], |slice| {
+///     slice.indices().map(|i| slice.get(i)).sum::<i32>()
+/// });
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// An index checked against one slice can't be used with another,
+/// even one at least as long:
+///
+/// ```compile_fail
+/// use sigma_types::brand;
+///
+/// brand(&[1, 2, 3], |short| {
+///     brand(&[4, 5, 6, 7], |long| {
+///         let i = short.index(2).unwrap();
+///         *long.get(i)
+///     })
+/// });
+/// ```
+#[inline]
+pub fn brand<'s, T, Output, F: for<'id> FnOnce(Branded<'id, 's, T>) -> Output>(
+    slice: &'s [T],
+    f: F,
+) -> Output {
+    f(Branded {
+        brand: PhantomData,
+        slice,
+    })
+}
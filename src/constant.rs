@@ -0,0 +1,143 @@
+//! Zero-sized types standing for constants
+//! of types that can't be const generics (e.g. floats).
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{One, Zero},
+    core::{f32, f64},
+};
+
+/// Zero-sized type standing for a constant of type `T`.
+pub trait Const<T> {
+    /// Value of this constant.
+    const VALUE: T;
+}
+
+/// `N` times machine epsilon,
+/// e.g. as a tolerance for rounding error accumulated over about `N` operations.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Epsilons<const N: u16>;
+
+impl<const N: u16> Const<f32> for Epsilons<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: f32 = (N as f32) * f32::EPSILON;
+}
+
+impl<const N: u16> Const<f64> for Epsilons<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: f64 = (N as f64) * f64::EPSILON;
+}
+
+/// An integer, as any type that can represent it exactly.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstInt<const N: i16>;
+
+impl<const N: i16> Const<i16> for ConstInt<N> {
+    const VALUE: i16 = N;
+}
+
+impl<const N: i16> Const<i32> for ConstInt<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: i32 = N as i32;
+}
+
+impl<const N: i16> Const<i64> for ConstInt<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: i64 = N as i64;
+}
+
+impl<const N: i16> Const<f32> for ConstInt<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: f32 = N as f32;
+}
+
+impl<const N: i16> Const<f64> for ConstInt<N> {
+    #[expect(clippy::as_conversions, reason = "lossless, but `From` isn't `const`")]
+    const VALUE: f64 = N as f64;
+}
+
+/// Zero of any type that has one.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstZero;
+
+impl<T: Zero> Const<T> for ConstZero {
+    const VALUE: T = T::ZERO;
+}
+
+/// One of any type that has one.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ConstOne;
+
+impl<T: One> Const<T> for ConstOne {
+    const VALUE: T = T::ONE;
+}
+
+/// Negative one.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MinusOne;
+
+/// Implement `MinusOne` for a signed primitive type.
+macro_rules! impl_minus_one {
+    ($t:ident, $minus_one:literal) => {
+        impl Const<$t> for MinusOne {
+            const VALUE: $t = $minus_one;
+        }
+    };
+}
+
+impl_minus_one!(i8, -1_i8);
+impl_minus_one!(i16, -1_i16);
+impl_minus_one!(i32, -1_i32);
+impl_minus_one!(i64, -1_i64);
+impl_minus_one!(i128, -1_i128);
+impl_minus_one!(isize, -1_isize);
+impl_minus_one!(f32, -1_f32);
+impl_minus_one!(f64, -1_f64);
+
+/// Negative π.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MinusPi;
+
+impl Const<f32> for MinusPi {
+    const VALUE: f32 = -f32::consts::PI;
+}
+
+impl Const<f64> for MinusPi {
+    const VALUE: f64 = -f64::consts::PI;
+}
+
+/// The ratio of a circle's circumference to its diameter (π).
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pi;
+
+impl Const<f32> for Pi {
+    const VALUE: f32 = f32::consts::PI;
+}
+
+impl Const<f64> for Pi {
+    const VALUE: f64 = f64::consts::PI;
+}
+
+/// The ratio of a circle's circumference to its radius (τ = 2π).
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Tau;
+
+impl Const<f32> for Tau {
+    const VALUE: f32 = f32::consts::TAU;
+}
+
+impl Const<f64> for Tau {
+    const VALUE: f64 = f64::consts::TAU;
+}
@@ -0,0 +1,105 @@
+//! Iterable data structure in which the number of elements
+//! satisfying a given invariant itself satisfies another invariant.
+
+use core::{fmt, marker::PhantomData};
+
+/// Iterable data structure in which the number of elements
+/// satisfying a given invariant itself satisfies another invariant.
+///
+/// For example, "exactly one primary-key column" is
+/// `Count<IsPrimaryKey, Vec<Column>, usize::EqualToInvariant<1>>`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Count<
+    Invariant: crate::Test<Input::Item, 1>,
+    Input: IntoIterator + fmt::Debug,
+    Bound: crate::Test<usize, 1>,
+>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+    PhantomData<Bound>,
+)
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>;
+
+impl<
+    Invariant: crate::Test<Input::Item, 1>,
+    Input: IntoIterator + fmt::Debug,
+    Bound: crate::Test<usize, 1>,
+> crate::Test<Input, 1> for Count<Invariant, Input, Bound>
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    const ADJECTIVE: &str = "valid the right number of times";
+
+    type Error<'i>
+        = WrongCount<Input::Item, Invariant, Bound>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let mut satisfied: usize = 0;
+        let mut total: usize = 0;
+        for element in input {
+            if Invariant::test([element]).is_ok() {
+                satisfied = satisfied.saturating_add(1);
+            }
+            total = total.saturating_add(1);
+        }
+        if Bound::test([&satisfied]).is_ok() {
+            Ok(())
+        } else {
+            Err(WrongCount {
+                phantom_bound: PhantomData,
+                phantom_invariant: PhantomData,
+                phantom_item: PhantomData,
+                satisfied,
+                total,
+            })
+        }
+    }
+}
+
+/// The number of elements in an iterator that satisfied
+/// a given invariant did not itself satisfy another invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WrongCount<
+    Item: fmt::Debug,
+    Invariant: crate::Test<Item, 1>,
+    Bound: crate::Test<usize, 1>,
+> {
+    /// Only to silence compiler errors.
+    phantom_bound: PhantomData<Bound>,
+    /// Only to silence compiler errors.
+    phantom_invariant: PhantomData<Invariant>,
+    /// Only to silence compiler errors.
+    phantom_item: PhantomData<Item>,
+    /// How many elements satisfied the invariant.
+    satisfied: usize,
+    /// How many elements were checked in total.
+    total: usize,
+}
+
+impl<Item: fmt::Debug, Invariant: crate::Test<Item, 1>, Bound: crate::Test<usize, 1>> fmt::Display
+    for WrongCount<Item, Invariant, Bound>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            satisfied, total, ..
+        } = *self;
+        write!(
+            f,
+            "{satisfied} of {total} element(s) were {}, but {satisfied} is not {}",
+            Invariant::ADJECTIVE,
+            Bound::ADJECTIVE,
+        )?;
+        match Bound::test([&self.satisfied]) {
+            Ok(()) => Ok(()),
+            Err(error) => write!(f, ": {error}"),
+        }
+    }
+}
@@ -0,0 +1,422 @@
+//! Bytes and text that are valid encodings (e.g. UTF-8, hexadecimal, or base64)
+//! and can therefore be decoded without an error path.
+//!
+//! Decoding checks nothing that the invariant hasn't already checked,
+//! so it panics only if a value was constructed without checking in a release build.
+
+use {
+    crate::{Sigma, Test, Text},
+    core::{fmt, iter, marker::PhantomData, str},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+/// Alphabet for base64 (RFC 4648) encoding.
+///
+/// The first 62 digits are always `A`-`Z`, `a`-`z`, then `0`-`9`;
+/// alphabets differ only in the last two digits and in whether they pad.
+pub trait Base64Alphabet {
+    /// Digits for the values 62 and 63, respectively.
+    const DIGITS_62_AND_63: [char; 2];
+
+    /// Whether encoded text is padded with `=` to a multiple of four characters.
+    const PADDED: bool;
+}
+
+/// Text that is valid base64.
+pub type Base64<Input, Alphabet> = Sigma<Input, Base64Invariant<Input, Alphabet>>;
+
+impl<Input: Text + fmt::Debug, Alphabet: Base64Alphabet> Base64<Input, Alphabet> {
+    /// Decode into a vector of bytes.
+    /// # Panics
+    /// If this text is not valid base64, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self) -> Vec<u8> {
+        self.decoded().collect()
+    }
+
+    /// Decode lazily, one byte at a time, without allocating.
+    /// # Panics
+    /// If this text is not valid base64, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    pub fn decoded(&self) -> impl Iterator<Item = u8> {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let mut chars = self.get_ref().char_offsets();
+        let mut buffer = 0_u32;
+        let mut bits = 0_u32;
+        iter::from_fn(move || {
+            while bits < 8 {
+                let (_, character) = chars.next()?;
+                if character == '=' {
+                    return None;
+                }
+                let Some(sextet) = sextet::<Alphabet>(character) else {
+                    panic!("`Base64` contained {character:?}");
+                };
+                buffer = buffer.wrapping_shl(6) | u32::from(sextet);
+                bits = bits.wrapping_add(6);
+            }
+            bits = bits.wrapping_sub(8);
+            #[expect(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                reason = "intentionally keeps only the lowest eight bits"
+            )]
+            Some(buffer.wrapping_shr(bits) as u8)
+        })
+    }
+}
+
+/// Text that is valid base64.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Base64Invariant<Input: Text + fmt::Debug, Alphabet: Base64Alphabet>(
+    PhantomData<Input>,
+    PhantomData<Alphabet>,
+);
+
+impl<Input: Text + fmt::Debug, Alphabet: Base64Alphabet> Test<Input, 1>
+    for Base64Invariant<Input, Alphabet>
+{
+    const ADJECTIVE: &str = "valid base64";
+
+    type Error<'i>
+        = NotBase64
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        // Every valid character is ASCII, so characters and bytes coincide:
+        let mut len = 0_usize;
+        let mut padding = 0_usize;
+        for (offset, character) in input.char_offsets() {
+            let valid = if Alphabet::PADDED && character == '=' && padding < 2 {
+                padding = padding.wrapping_add(1);
+                true
+            } else {
+                padding == 0 && sextet::<Alphabet>(character).is_some()
+            };
+            if !valid {
+                return Err(NotBase64::InvalidChar { character, offset });
+            }
+            len = len.wrapping_add(1);
+        }
+        let valid_len = if Alphabet::PADDED {
+            len.trailing_zeros() >= 2
+        } else {
+            (len & 3) != 1
+        };
+        if valid_len {
+            Ok(())
+        } else {
+            Err(NotBase64::InvalidLength { len })
+        }
+    }
+}
+
+/// Digits of the case that hexadecimal text must use.
+pub trait HexCase {
+    /// Value of a digit, if it's a digit of this case.
+    fn digit(character: char) -> Option<u8>;
+}
+
+/// Text that is valid hexadecimal, with two digits per byte.
+pub type Hex<Input, Case> = Sigma<Input, HexInvariant<Input, Case>>;
+
+impl<Input: Text + fmt::Debug, Case: HexCase> Hex<Input, Case> {
+    /// Decode into a vector of bytes.
+    /// # Panics
+    /// If this text is not valid hexadecimal, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn decode(&self) -> Vec<u8> {
+        self.decoded().collect()
+    }
+
+    /// Decode lazily, one byte at a time, without allocating.
+    /// # Panics
+    /// If this text is not valid hexadecimal, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    pub fn decoded(&self) -> impl Iterator<Item = u8> {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let mut digits = self.get_ref().char_offsets().map(|(_, character)| {
+            let Some(digit) = Case::digit(character) else {
+                panic!("`Hex` contained {character:?}");
+            };
+            digit
+        });
+        iter::from_fn(move || {
+            let high = digits.next()?;
+            let Some(low) = digits.next() else {
+                panic!("`Hex` had an odd number of digits");
+            };
+            Some(high.wrapping_shl(4) | low)
+        })
+    }
+}
+
+/// Text that is valid hexadecimal, with two digits per byte.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HexInvariant<Input: Text + fmt::Debug, Case: HexCase>(
+    PhantomData<Input>,
+    PhantomData<Case>,
+);
+
+impl<Input: Text + fmt::Debug, Case: HexCase> Test<Input, 1> for HexInvariant<Input, Case> {
+    const ADJECTIVE: &str = "valid hexadecimal";
+
+    type Error<'i>
+        = NotHex
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        // Every valid character is ASCII, so characters and bytes coincide:
+        let mut len = 0_usize;
+        for (offset, character) in input.char_offsets() {
+            if Case::digit(character).is_none() {
+                return Err(NotHex::InvalidChar { character, offset });
+            }
+            len = len.wrapping_add(1);
+        }
+        if (len & 1) == 0 {
+            Ok(())
+        } else {
+            Err(NotHex::OddLength { len })
+        }
+    }
+}
+
+/// Hexadecimal digits in lowercase (e.g. `ff`).
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Lower;
+
+impl HexCase for Lower {
+    #[inline]
+    fn digit(character: char) -> Option<u8> {
+        if character.is_ascii_uppercase() {
+            None
+        } else {
+            Mixed::digit(character)
+        }
+    }
+}
+
+/// Hexadecimal digits in either case (e.g. `ff`, `FF`, or `fF`).
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mixed;
+
+impl HexCase for Mixed {
+    #[inline]
+    fn digit(character: char) -> Option<u8> {
+        let digit = character.to_digit(16)?;
+        u8::try_from(digit).ok()
+    }
+}
+
+/// Text that was not valid base64.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NotBase64 {
+    /// A character that is not a digit of this alphabet,
+    /// or padding where padding can't appear.
+    InvalidChar {
+        /// Offending character.
+        character: char,
+        /// Byte offset of the offending character.
+        offset: usize,
+    },
+    /// A length that no encoding in this alphabet can have.
+    InvalidLength {
+        /// Number of characters.
+        len: usize,
+    },
+}
+
+impl fmt::Display for NotBase64 {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        match *self {
+            Self::InvalidChar { character, offset } => write!(
+                f,
+                "{character:?} at byte offset {offset} can't appear there in base64",
+            ),
+            Self::InvalidLength { len } => {
+                write!(f, "No base64 encoding is {len} characters long")
+            }
+        }
+    }
+}
+
+/// Text that was not valid hexadecimal.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NotHex {
+    /// A character that is not a digit of the required case.
+    InvalidChar {
+        /// Offending character.
+        character: char,
+        /// Byte offset of the offending character.
+        offset: usize,
+    },
+    /// An odd number of digits, which can't be split into bytes.
+    OddLength {
+        /// Number of digits.
+        len: usize,
+    },
+}
+
+impl fmt::Display for NotHex {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        match *self {
+            Self::InvalidChar { character, offset } => write!(
+                f,
+                "{character:?} at byte offset {offset} is not a hexadecimal digit of the right case",
+            ),
+            Self::OddLength { len } => write!(f, "An odd number of hexadecimal digits ({len})"),
+        }
+    }
+}
+
+/// Standard base64 alphabet (`+` and `/`), padded with `=`.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Standard;
+
+impl Base64Alphabet for Standard {
+    const DIGITS_62_AND_63: [char; 2] = ['+', '/'];
+    const PADDED: bool = true;
+}
+
+/// Hexadecimal digits in uppercase (e.g. `FF`).
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Upper;
+
+impl HexCase for Upper {
+    #[inline]
+    fn digit(character: char) -> Option<u8> {
+        if character.is_ascii_lowercase() {
+            None
+        } else {
+            Mixed::digit(character)
+        }
+    }
+}
+
+/// URL- and filename-safe base64 alphabet (`-` and `_`), without padding.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UrlSafe;
+
+impl Base64Alphabet for UrlSafe {
+    const DIGITS_62_AND_63: [char; 2] = ['-', '_'];
+    const PADDED: bool = false;
+}
+
+/// Bytes that are valid UTF-8.
+pub type Utf8Bytes<Input> = Sigma<Input, Utf8BytesInvariant<Input>>;
+
+impl<Input: AsRef<[u8]> + fmt::Debug> Utf8Bytes<Input> {
+    /// View these bytes as a string slice.
+    /// # Panics
+    /// If these bytes are not valid UTF-8, which is possible only if
+    /// they were constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        match str::from_utf8(self.get_ref().as_ref()) {
+            Ok(s) => s,
+            Err(e) => panic!("`Utf8Bytes` was not UTF-8: {e}"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Utf8Bytes<Vec<u8>> {
+    /// Convert these bytes into a string without copying.
+    /// # Panics
+    /// If these bytes are not valid UTF-8, which is possible only if
+    /// they were constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        match String::from_utf8(self.get()) {
+            Ok(s) => s,
+            Err(e) => panic!("`Utf8Bytes` was not UTF-8: {e}"),
+        }
+    }
+}
+
+/// Bytes that are valid UTF-8.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Utf8BytesInvariant<Input: AsRef<[u8]> + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: AsRef<[u8]> + fmt::Debug> Test<Input, 1> for Utf8BytesInvariant<Input> {
+    const ADJECTIVE: &str = "valid UTF-8";
+
+    type Error<'i>
+        = str::Utf8Error
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        str::from_utf8(input.as_ref()).map(|_| ())
+    }
+}
+
+/// Value of a base64 digit, if it's a digit of this alphabet.
+#[inline]
+fn sextet<Alphabet: Base64Alphabet>(character: char) -> Option<u8> {
+    let [digit_62, digit_63] = Alphabet::DIGITS_62_AND_63;
+    let byte = u8::try_from(character).ok()?;
+    match byte {
+        b'A'..=b'Z' => Some(byte.wrapping_sub(b'A')),
+        b'a'..=b'z' => Some(byte.wrapping_sub(b'a').wrapping_add(26)),
+        b'0'..=b'9' => Some(byte.wrapping_sub(b'0').wrapping_add(52)),
+        _ if character == digit_62 => Some(62),
+        _ if character == digit_63 => Some(63),
+        _ => None,
+    }
+}
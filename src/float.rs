@@ -0,0 +1,187 @@
+//! Floating-point functions restricted to domains on which their outputs are proven.
+//!
+//! Where a function can underflow to zero or overflow to infinity,
+//! its output type is the weakest one that holds even then:
+//! for example, `Finite::proven_exp` is `NonNegative`, not `Positive`,
+//! since `exp` of a large negative number rounds to zero.
+//!
+//! Methods that share a name with one on `f32` or `f64` take a `proven_` prefix,
+//! so that, e.g., `x.abs()` still dereferences to the primitive method.
+
+use crate::{Finite, Negative, NonNegative, NonPositive, NonZero, Positive};
+
+#[cfg(feature = "std")]
+use crate::Zero as _;
+
+/// Implement each method for a primitive floating-point type.
+macro_rules! impl_float {
+    ($f:ident) => {
+        impl Finite<$f> {
+            /// Absolute value.
+            #[inline]
+            #[must_use]
+            pub fn proven_abs(self) -> NonNegative<$f> {
+                self.map($f::abs)
+            }
+
+            /// Either `1` or `-1`, depending on the sign (including the sign of zero).
+            #[inline]
+            #[must_use]
+            pub fn proven_signum(self) -> NonZero<$f> {
+                self.map($f::signum)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Finite<$f> {
+            /// Raise to an even integer power.
+            ///
+            /// Fails to compile unless `N` is even.
+            #[inline]
+            #[must_use]
+            pub fn powi_even<const N: i32>(self) -> NonNegative<$f> {
+                const { assert!((N & 1_i32) == 0_i32, "exponent must be even") };
+                self.map(|x| x.powi(N))
+            }
+
+            /// Exponential function.
+            ///
+            /// Not `Positive`, since very negative inputs underflow to zero.
+            #[inline]
+            #[must_use]
+            pub fn proven_exp(self) -> NonNegative<$f> {
+                self.map($f::exp)
+            }
+
+            /// Natural logarithm, or `None` unless this value is positive.
+            ///
+            /// Defined on `Finite` rather than `Positive`,
+            /// since the logarithm of infinity is infinite.
+            #[inline]
+            #[must_use]
+            pub fn proven_ln(self) -> Option<Finite<$f>> {
+                let x = self.get();
+                (x > $f::ZERO).then(|| Finite::new(x.ln()))
+            }
+        }
+
+        impl Negative<$f> {
+            /// Absolute value.
+            #[inline]
+            #[must_use]
+            pub fn proven_abs(self) -> Positive<$f> {
+                self.map($f::abs)
+            }
+
+            /// Reciprocal (`1 / x`).
+            ///
+            /// Not `Negative`, since the reciprocal of negative infinity is negative zero.
+            #[inline]
+            #[must_use]
+            pub fn proven_recip(self) -> NonPositive<$f> {
+                self.map($f::recip)
+            }
+
+            /// Always `-1`.
+            #[inline]
+            #[must_use]
+            pub fn proven_signum(self) -> Negative<$f> {
+                self.map($f::signum)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Negative<$f> {
+            /// Raise to an even integer power.
+            ///
+            /// Not `Positive`, since the result can underflow to zero.
+            /// Fails to compile unless `N` is even.
+            #[inline]
+            #[must_use]
+            pub fn powi_even<const N: i32>(self) -> NonNegative<$f> {
+                const { assert!((N & 1_i32) == 0_i32, "exponent must be even") };
+                self.map(|x| x.powi(N))
+            }
+
+            /// Raise to an odd integer power.
+            ///
+            /// Not `Negative`, since the result can underflow to zero.
+            /// Fails to compile unless `N` is odd.
+            #[inline]
+            #[must_use]
+            pub fn powi_odd<const N: i32>(self) -> NonPositive<$f> {
+                const { assert!((N & 1_i32) != 0_i32, "exponent must be odd") };
+                self.map(|x| x.powi(N))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl NonNegative<$f> {
+            /// Raise to an integer power.
+            ///
+            /// Negative zero is treated as positive zero,
+            /// since, e.g., `(-0.0).powi(-1)` is negative infinity.
+            #[inline]
+            #[must_use]
+            pub fn proven_powi(self, n: i32) -> NonNegative<$f> {
+                self.map(|x| x.abs().powi(n))
+            }
+
+            /// Square root.
+            #[inline]
+            #[must_use]
+            pub fn proven_sqrt(self) -> NonNegative<$f> {
+                self.map($f::sqrt)
+            }
+        }
+
+        impl NonPositive<$f> {
+            /// Absolute value.
+            #[inline]
+            #[must_use]
+            pub fn proven_abs(self) -> NonNegative<$f> {
+                self.map($f::abs)
+            }
+        }
+
+        impl Positive<$f> {
+            /// Reciprocal (`1 / x`).
+            ///
+            /// Not `Positive`, since the reciprocal of infinity is zero.
+            #[inline]
+            #[must_use]
+            pub fn proven_recip(self) -> NonNegative<$f> {
+                self.map($f::recip)
+            }
+
+            /// Always `1`.
+            #[inline]
+            #[must_use]
+            pub fn proven_signum(self) -> Positive<$f> {
+                self.map($f::signum)
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Positive<$f> {
+            /// Raise to an integer power.
+            ///
+            /// Not `Positive`, since the result can underflow to zero.
+            #[inline]
+            #[must_use]
+            pub fn proven_powi(self, n: i32) -> NonNegative<$f> {
+                self.map(|x| x.powi(n))
+            }
+
+            /// Square root.
+            #[inline]
+            #[must_use]
+            pub fn proven_sqrt(self) -> Positive<$f> {
+                self.map($f::sqrt)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
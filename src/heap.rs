@@ -0,0 +1,50 @@
+//! Binary heaps stored in breadth-first order (e.g. by `std::collections::BinaryHeap`).
+
+use {
+    crate::{OutOfOrder, ParentChild},
+    core::cmp::Ordering,
+};
+
+/// Binary heap in which each parent is at least as large as its children,
+/// so the first element is the largest.
+pub type MaxHeap<Input> = crate::Sigma<Input, MaxHeapInvariant<Input>>;
+
+/// Binary heap in which each parent is at least as large as its children,
+/// so the first element is the largest.
+pub type MaxHeapInvariant<Input> = ParentChild<HeapPair<true>, Input>;
+
+/// Binary heap in which each parent is at most as large as its children,
+/// so the first element is the smallest.
+pub type MinHeap<Input> = crate::Sigma<Input, MinHeapInvariant<Input>>;
+
+/// Binary heap in which each parent is at most as large as its children,
+/// so the first element is the smallest.
+pub type MinHeapInvariant<Input> = ParentChild<HeapPair<false>, Input>;
+
+/// (Parent, child) pair in heap order:
+/// the parent is at least (if `MAX`) or at most (otherwise) as large as the child.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HeapPair<const MAX: bool>;
+
+impl<const MAX: bool, Input: PartialOrd> crate::Test<Input, 2> for HeapPair<MAX> {
+    const ADJECTIVE: &str = "heap-ordered";
+
+    type Error<'i>
+        = OutOfOrder
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([parent, child]: [&Input; 2]) -> Result<(), Self::Error<'_>> {
+        parent
+            .partial_cmp(child)
+            .map_or(Err(OutOfOrder::NoDefinedComparison), |ordering| {
+                if ordering == Ordering::Equal || (ordering == Ordering::Greater) == MAX {
+                    Ok(())
+                } else {
+                    Err(OutOfOrder::Reversed)
+                }
+            })
+    }
+}
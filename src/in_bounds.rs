@@ -0,0 +1,51 @@
+//! Indices less than the length of a slice known only at runtime.
+
+use {
+    crate::{SigmaWith, TestWith},
+    core::fmt,
+};
+
+/// Indices less than the length of a slice known only at runtime.
+pub type InBounds<T> = SigmaWith<usize, InBoundsInvariant, [T]>;
+
+/// Indices less than the length of a slice known only at runtime.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InBoundsInvariant;
+
+impl<T> TestWith<[T], usize> for InBoundsInvariant {
+    const ADJECTIVE: &str = "in bounds";
+
+    type Error<'i>
+        = NotInBounds<'i>
+    where
+        T: 'i;
+
+    #[inline(always)]
+    fn test_with<'i>(input: &'i usize, ctx: &'i [T]) -> Result<(), Self::Error<'i>> {
+        let len = ctx.len();
+        if *input < len {
+            Ok(())
+        } else {
+            Err(NotInBounds { index: input, len })
+        }
+    }
+}
+
+/// An index expected to be less than the length of a slice was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotInBounds<'i> {
+    /// Offending index.
+    index: &'i usize,
+    /// Length of the slice.
+    len: usize,
+}
+
+impl fmt::Display for NotInBounds<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { index, len } = *self;
+        write!(f, "Index {index} is out of bounds for length {len}")
+    }
+}
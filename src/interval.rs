@@ -0,0 +1,106 @@
+//! Terms on an interval between two constants,
+//! either inclusive or exclusive at each extreme.
+
+use {
+    crate::{Const, Sigma, Test},
+    core::{cmp::Ordering, fmt, marker::PhantomData},
+};
+
+/// Terms on an interval between two constants,
+/// either inclusive or exclusive at each extreme.
+///
+/// Bounds are types implementing `Const` (e.g. `MinusOne` or `Pi`),
+/// since floating-point values can't be const generics.
+pub type Interval<Input, Lo, Hi, const INCLUSIVE_AT_LO: bool, const INCLUSIVE_AT_HI: bool> =
+    Sigma<Input, IntervalInvariant<Input, Lo, Hi, INCLUSIVE_AT_LO, INCLUSIVE_AT_HI>>;
+
+/// Terms on an interval between two constants,
+/// either inclusive or exclusive at each extreme.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IntervalInvariant<
+    Input: PartialOrd + fmt::Debug,
+    Lo: Const<Input>,
+    Hi: Const<Input>,
+    const INCLUSIVE_AT_LO: bool,
+    const INCLUSIVE_AT_HI: bool,
+>(PhantomData<Input>, PhantomData<Lo>, PhantomData<Hi>);
+
+impl<
+    Input: PartialOrd + fmt::Debug,
+    Lo: Const<Input>,
+    Hi: Const<Input>,
+    const INCLUSIVE_AT_LO: bool,
+    const INCLUSIVE_AT_HI: bool,
+> Test<Input, 1> for IntervalInvariant<Input, Lo, Hi, INCLUSIVE_AT_LO, INCLUSIVE_AT_HI>
+{
+    const ADJECTIVE: &str = "on the interval";
+
+    type Error<'i>
+        = NotInInterval<'i, Input, INCLUSIVE_AT_LO, INCLUSIVE_AT_HI>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let above_lo = match input.partial_cmp(&Lo::VALUE) {
+            None | Some(Ordering::Less) => false,
+            Some(Ordering::Equal) => INCLUSIVE_AT_LO,
+            Some(Ordering::Greater) => true,
+        };
+        let below_hi = match input.partial_cmp(&Hi::VALUE) {
+            None | Some(Ordering::Greater) => false,
+            Some(Ordering::Equal) => INCLUSIVE_AT_HI,
+            Some(Ordering::Less) => true,
+        };
+        if above_lo && below_hi {
+            Ok(())
+        } else {
+            Err(NotInInterval {
+                hi: Hi::VALUE,
+                input,
+                lo: Lo::VALUE,
+            })
+        }
+    }
+}
+
+/// A term expected to be on an interval was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotInInterval<
+    'i,
+    Input: fmt::Debug,
+    const INCLUSIVE_AT_LO: bool,
+    const INCLUSIVE_AT_HI: bool,
+> {
+    /// Upper bound of the interval.
+    hi: Input,
+    /// Offending term.
+    input: &'i Input,
+    /// Lower bound of the interval.
+    lo: Input,
+}
+
+impl<Input: fmt::Debug, const INCLUSIVE_AT_LO: bool, const INCLUSIVE_AT_HI: bool> fmt::Display
+    for NotInInterval<'_, Input, INCLUSIVE_AT_LO, INCLUSIVE_AT_HI>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            ref hi,
+            input,
+            ref lo,
+        } = *self;
+        write!(
+            f,
+            "Not on {}{lo:#?}, {hi:#?}{}: {input:#?}",
+            if INCLUSIVE_AT_LO { '[' } else { '(' },
+            if INCLUSIVE_AT_HI { ']' } else { ')' },
+        )
+    }
+}
@@ -24,3 +24,28 @@ pub trait Test<Input, const ARITY: usize = 1> {
     /// If it doesn't.
     fn test(input: [&Input; ARITY]) -> Result<(), Self::Error<'_>>;
 }
+
+/// Function-like type that checks an invariant against a runtime context
+/// (e.g. "less than the length of this slice")
+/// and optionally provides an error message.
+///
+/// The context is not stored alongside the checked term,
+/// so it's up to the caller to check against the context they mean.
+pub trait TestWith<Ctx: ?Sized, Input> {
+    /// Adjective to describe this test:
+    /// for example, if we're testing A,
+    /// then this is B in "A is not B."
+    const ADJECTIVE: &str;
+
+    /// An error implementing `::core::fmt::Display`.
+    /// If no error is ever provided, please use `::core::convert::Infallible`.
+    type Error<'i>: fmt::Display
+    where
+        Ctx: 'i,
+        Input: 'i;
+
+    /// Check whether a given term satisfies this invariant in a given context.
+    /// # Errors
+    /// If it doesn't.
+    fn test_with<'i>(input: &'i Input, ctx: &'i Ctx) -> Result<(), Self::Error<'i>>;
+}
@@ -0,0 +1,251 @@
+//! Iterator adapters that check each item for an invariant.
+
+use {
+    crate::{Sigma, Test},
+    core::{fmt, iter::FusedIterator, marker::PhantomData},
+};
+
+/// Iterator adapter that checks each item for an invariant
+/// if and only if debug assertions are enabled.
+/// See `SigmaIterExt::checked`.
+#[derive(Clone, Debug)]
+pub struct Checked<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> {
+    /// Iterator of unchecked items.
+    iter: Iter,
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+}
+
+impl<Iter: DoubleEndedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>>
+    DoubleEndedIterator for Checked<Iter, Invariant>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(Sigma::new)
+    }
+}
+
+impl<Iter: ExactSizeIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> ExactSizeIterator
+    for Checked<Iter, Invariant>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<Iter: FusedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> FusedIterator
+    for Checked<Iter, Invariant>
+{
+}
+
+impl<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> Iterator
+    for Checked<Iter, Invariant>
+{
+    type Item = Sigma<Iter::Item, Invariant>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Sigma::new)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Iterator adapter that silently drops each item that doesn't satisfy an invariant.
+/// See `SigmaIterExt::filter_valid`.
+#[derive(Clone, Debug)]
+pub struct FilterValid<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> {
+    /// Iterator of unchecked items.
+    iter: Iter,
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+}
+
+impl<Iter: DoubleEndedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>>
+    DoubleEndedIterator for FilterValid<Iter, Invariant>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Ok(sigma) = Sigma::try_new(self.iter.next_back()?) {
+                return Some(sigma);
+            }
+        }
+    }
+}
+
+impl<Iter: FusedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> FusedIterator
+    for FilterValid<Iter, Invariant>
+{
+}
+
+impl<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> Iterator
+    for FilterValid<Iter, Invariant>
+{
+    type Item = Sigma<Iter::Item, Invariant>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Ok(sigma) = Sigma::try_new(self.iter.next()?) {
+                return Some(sigma);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+/// A value that did not satisfy an invariant, returned by value.
+///
+/// Since the invariant's error would borrow this value,
+/// the error is recomputed whenever this is displayed.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rejected<Raw: fmt::Debug, Invariant: Test<Raw, 1>> {
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+    /// Value that did not satisfy the invariant.
+    raw: Raw,
+}
+
+impl<Raw: fmt::Debug, Invariant: Test<Raw, 1>> Rejected<Raw, Invariant> {
+    /// Unwrap the value that did not satisfy the invariant.
+    #[inline(always)]
+    pub fn get(self) -> Raw {
+        self.raw
+    }
+
+    /// Unwrap the value that did not satisfy the invariant.
+    #[inline(always)]
+    pub const fn get_ref(&self) -> &Raw {
+        &self.raw
+    }
+
+    /// Record that a value did not satisfy an invariant.
+    #[inline(always)]
+    pub(crate) const fn new(raw: Raw) -> Self {
+        Self {
+            phantom: PhantomData,
+            raw,
+        }
+    }
+}
+
+impl<Raw: fmt::Debug, Invariant: Test<Raw, 1>> fmt::Display for Rejected<Raw, Invariant> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        write!(f, "{:#?} is not {}", self.raw, Invariant::ADJECTIVE)?;
+        match Invariant::test([&self.raw]) {
+            Ok(()) => Ok(()),
+            Err(error) => write!(f, ": {error}"),
+        }
+    }
+}
+
+/// Extension trait to check each item of an iterator for an invariant.
+pub trait SigmaIterExt: Iterator<Item: fmt::Debug> + Sized {
+    /// Check each item for an invariant
+    /// if and only if debug assertions are enabled.
+    ///
+    /// Items are checked lazily, as they're yielded.
+    /// # Panics
+    /// On the first item that does not satisfy the invariant
+    /// ***and*** only if debug assertions are enabled.
+    #[inline(always)]
+    fn checked<Invariant: Test<Self::Item, 1>>(self) -> Checked<Self, Invariant> {
+        Checked {
+            iter: self,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Silently drop each item that does not satisfy an invariant.
+    ///
+    /// Unlike `checked`, this always checks, even in release builds.
+    #[inline(always)]
+    fn filter_valid<Invariant: Test<Self::Item, 1>>(self) -> FilterValid<Self, Invariant> {
+        FilterValid {
+            iter: self,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Check each item for an invariant without panicking.
+    ///
+    /// Unlike `checked`, this always checks, even in release builds.
+    #[inline(always)]
+    fn try_checked<Invariant: Test<Self::Item, 1>>(self) -> TryChecked<Self, Invariant> {
+        TryChecked {
+            iter: self,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<Iter: Iterator<Item: fmt::Debug>> SigmaIterExt for Iter {}
+
+/// Iterator adapter that checks each item for an invariant without panicking.
+/// See `SigmaIterExt::try_checked`.
+#[derive(Clone, Debug)]
+pub struct TryChecked<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> {
+    /// Iterator of unchecked items.
+    iter: Iter,
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+}
+
+impl<Iter: DoubleEndedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>>
+    DoubleEndedIterator for TryChecked<Iter, Invariant>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|raw| Sigma::try_new(raw).map_err(Rejected::new))
+    }
+}
+
+impl<Iter: ExactSizeIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> ExactSizeIterator
+    for TryChecked<Iter, Invariant>
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<Iter: FusedIterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> FusedIterator
+    for TryChecked<Iter, Invariant>
+{
+}
+
+impl<Iter: Iterator<Item: fmt::Debug>, Invariant: Test<Iter::Item, 1>> Iterator
+    for TryChecked<Iter, Invariant>
+{
+    type Item = Result<Sigma<Iter::Item, Invariant>, Rejected<Iter::Item, Invariant>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|raw| Sigma::try_new(raw).map_err(Rejected::new))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,259 @@
+//! Collections whose length is bounded by constants
+//! (between fixed-size arrays and fully dynamic collections).
+
+use {
+    crate::{Sigma, Test},
+    core::{fmt, marker::PhantomData},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::VecDeque, string::String, vec::Vec};
+
+/// Collections with a length (e.g. the number of elements in a `Vec` or bytes in a `String`).
+pub trait Length {
+    /// Number of elements (or, for text, bytes).
+    fn length(&self) -> usize;
+}
+
+impl<T> Length for &[T] {
+    #[inline(always)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Length for &str {
+    #[inline(always)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Length for String {
+    #[inline(always)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Length for Vec<T> {
+    #[inline(always)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Length for VecDeque<T> {
+    #[inline(always)]
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Collections whose length satisfies a given invariant
+/// (e.g. `Exactly<N>`, `AtMost<N>`, or any other `Test<usize, 1>`).
+pub type Len<Input, Bound> = Sigma<Input, LenInvariant<Input, Bound>>;
+
+/// Collections with at least `N` elements.
+pub type LenAtLeast<Input, const N: usize> = Len<Input, AtLeast<N>>;
+
+/// Collections with at most `N` elements.
+pub type LenAtMost<Input, const N: usize> = Len<Input, AtMost<N>>;
+
+/// Collections with between `LO` and `HI` elements, inclusive.
+pub type LenInRange<Input, const LO: usize, const HI: usize> = Len<Input, InRange<LO, HI>>;
+
+impl<'a, T: fmt::Debug, const N: usize> Len<&'a [T], Exactly<N>> {
+    /// View as a fixed-size array.
+    /// # Panics
+    /// If this slice does not have exactly `N` elements, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn as_array(&self) -> &'a [T; N] {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let slice: &'a [T] = self.get_ref();
+        slice
+            .try_into()
+            .unwrap_or_else(|_| panic!("slice does not have exactly {N} elements"))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug, const N: usize> Len<Vec<T>, Exactly<N>> {
+    /// Convert into a fixed-size array, which can't fail.
+    /// # Panics
+    /// If this vector does not have exactly `N` elements, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn into_array(self) -> [T; N] {
+        #![expect(
+            clippy::panic,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        self.get()
+            .try_into()
+            .unwrap_or_else(|_| panic!("vector does not have exactly {N} elements"))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LO: usize, const HI: usize> Len<String, InRange<LO, HI>> {
+    /// Append a character, or return it if that would make this string longer than `HI` bytes.
+    /// # Errors
+    /// If that would make this string longer than `HI` bytes.
+    #[inline]
+    pub fn push(&mut self, character: char) -> Result<(), char> {
+        if self.len().saturating_add(character.len_utf8()) > HI {
+            return Err(character);
+        }
+        self.map_mut(|string| string.push(character));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug, const LO: usize, const HI: usize> Len<Vec<T>, InRange<LO, HI>> {
+    /// Append an element, or return it if this vector already has `HI` elements.
+    /// # Errors
+    /// If this vector already has `HI` elements.
+    #[inline]
+    pub fn push(&mut self, element: T) -> Result<(), T> {
+        if self.len() >= HI {
+            return Err(element);
+        }
+        self.map_mut(|vec| vec.push(element));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: fmt::Debug, const LO: usize, const HI: usize> Len<VecDeque<T>, InRange<LO, HI>> {
+    /// Append an element to the back, or return it if this deque already has `HI` elements.
+    /// # Errors
+    /// If this deque already has `HI` elements.
+    #[inline]
+    pub fn push_back(&mut self, element: T) -> Result<(), T> {
+        if self.len() >= HI {
+            return Err(element);
+        }
+        self.map_mut(|deque| deque.push_back(element));
+        Ok(())
+    }
+
+    /// Prepend an element to the front, or return it if this deque already has `HI` elements.
+    /// # Errors
+    /// If this deque already has `HI` elements.
+    #[inline]
+    pub fn push_front(&mut self, element: T) -> Result<(), T> {
+        if self.len() >= HI {
+            return Err(element);
+        }
+        self.map_mut(|deque| deque.push_front(element));
+        Ok(())
+    }
+}
+
+/// Collections whose length satisfies a given invariant
+/// (e.g. `Exactly<N>`, `AtMost<N>`, or any other `Test<usize, 1>`).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LenInvariant<Input: Length + fmt::Debug, Bound: Test<usize, 1>>(
+    PhantomData<Input>,
+    PhantomData<Bound>,
+);
+
+impl<Input: Length + fmt::Debug, Bound: Test<usize, 1>> Test<Input, 1>
+    for LenInvariant<Input, Bound>
+{
+    const ADJECTIVE: &str = "of a valid length";
+
+    type Error<'i>
+        = WrongLen<Bound>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let len = input.length();
+        if Bound::test([&len]).is_ok() {
+            Ok(())
+        } else {
+            Err(WrongLen {
+                len,
+                phantom_bound: PhantomData,
+            })
+        }
+    }
+}
+
+/// The length of a collection did not satisfy a given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WrongLen<Bound: Test<usize, 1>> {
+    /// Length of the offending collection.
+    len: usize,
+    /// Only to silence compiler errors.
+    phantom_bound: PhantomData<Bound>,
+}
+
+impl<Bound: Test<usize, 1>> fmt::Display for WrongLen<Bound> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { len, .. } = *self;
+        write!(f, "Length {len} is not {}", Bound::ADJECTIVE)?;
+        match Bound::test([&self.len]) {
+            Ok(()) => Ok(()),
+            Err(error) => write!(f, ": {error}"),
+        }
+    }
+}
+
+/// Exactly `N`.
+pub type Exactly<const N: usize> = InRange<N, N>;
+
+/// At least `N`.
+pub type AtLeast<const N: usize> = InRange<N, { usize::MAX }>;
+
+/// At most `N`.
+pub type AtMost<const N: usize> = InRange<0, N>;
+
+/// Between `LO` and `HI`, inclusive.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct InRange<const LO: usize, const HI: usize>;
+
+impl<const LO: usize, const HI: usize> Test<usize, 1> for InRange<LO, HI> {
+    const ADJECTIVE: &str = "in the range";
+
+    type Error<'i> = NotInRange<'i, LO, HI>;
+
+    #[inline(always)]
+    fn test([input]: [&usize; 1]) -> Result<(), Self::Error<'_>> {
+        if (LO..=HI).contains(input) {
+            Ok(())
+        } else {
+            Err(NotInRange(input))
+        }
+    }
+}
+
+/// A term expected to be between two constants (inclusive) was not.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotInRange<'i, const LO: usize, const HI: usize>(&'i usize);
+
+impl<const LO: usize, const HI: usize> fmt::Display for NotInRange<'_, LO, HI> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(z) = *self;
+        write!(f, "{z} is not in {LO}..={HI}")
+    }
+}
@@ -1,4 +1,4 @@
-//! Terms less than a constant (defined by `PartialOrd` comparison).
+//! Terms compared to a constant (e.g. less than, equal to, or a multiple of it).
 
 #![expect(
     clippy::arbitrary_source_item_ordering,
@@ -9,13 +9,133 @@
 macro_rules! mk_mod {
     ($t:ident) => {
         pub mod $t {
-            //! Terms of type `$t` less than a constant (defined by `PartialOrd` comparison).
+            //! Terms of type `$t` compared to a constant
+            //! (e.g. less than, equal to, or a multiple of it).
 
             use {
-                crate::{Sigma, Test},
-                core::fmt,
+                crate::{Sigma, Test, Zero as _},
+                core::{fmt, ops},
             };
 
+            /// Terms equal to a constant (defined by `PartialEq` comparison).
+            pub type EqualTo<const N: $t> = Sigma<$t, EqualToInvariant<N>>;
+
+            /// Terms equal to a constant (defined by `PartialEq` comparison).
+            #[expect(clippy::exhaustive_structs, reason = "no fields")]
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct EqualToInvariant<const N: $t>;
+
+            impl<const N: $t> Test<$t, 1> for EqualToInvariant<N> {
+                const ADJECTIVE: &str = "equal to the constant";
+                type Error<'i>
+                    = NotEqualTo<'i, N>
+                where
+                    $t: 'i;
+
+                #[inline(always)]
+                fn test([input]: [&$t; 1]) -> Result<(), Self::Error<'_>> {
+                    if *input == N {
+                        Ok(())
+                    } else {
+                        Err(NotEqualTo(input))
+                    }
+                }
+            }
+
+            /// A term expected to equal a constant did not.
+            #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct NotEqualTo<'i, const N: $t>(&'i $t);
+
+            impl<const N: $t> fmt::Display for NotEqualTo<'_, N> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let Self(z) = *self;
+                    write!(f, "{z:#?} != {N:#?}")
+                }
+            }
+
+            /// Terms that are multiples of a constant (defined by remainder).
+            pub type MultipleOf<const N: $t> = Sigma<$t, MultipleOfInvariant<N>>;
+
+            impl<const N: $t> ops::Add for MultipleOf<N> {
+                type Output = Self;
+
+                #[inline]
+                #[expect(
+                    clippy::arithmetic_side_effects,
+                    reason = "overflows exactly when the raw operation would"
+                )]
+                fn add(self, rhs: Self) -> Self {
+                    self.map(|lhs| lhs.add(rhs.get()))
+                }
+            }
+
+            impl<const N: $t> ops::Mul<$t> for MultipleOf<N> {
+                type Output = Self;
+
+                #[inline]
+                #[expect(
+                    clippy::arithmetic_side_effects,
+                    reason = "overflows exactly when the raw operation would"
+                )]
+                fn mul(self, rhs: $t) -> Self {
+                    self.map(|lhs| lhs.mul(rhs))
+                }
+            }
+
+            impl<const N: $t> ops::Sub for MultipleOf<N> {
+                type Output = Self;
+
+                #[inline]
+                #[expect(
+                    clippy::arithmetic_side_effects,
+                    reason = "overflows exactly when the raw operation would"
+                )]
+                fn sub(self, rhs: Self) -> Self {
+                    self.map(|lhs| lhs.sub(rhs.get()))
+                }
+            }
+
+            /// Terms that are multiples of a constant (defined by remainder).
+            #[expect(clippy::exhaustive_structs, reason = "no fields")]
+            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct MultipleOfInvariant<const N: $t>;
+
+            impl<const N: $t> Test<$t, 1> for MultipleOfInvariant<N> {
+                const ADJECTIVE: &str = "a multiple of the constant";
+                type Error<'i>
+                    = NotMultipleOf<'i, N>
+                where
+                    $t: 'i;
+
+                #[inline(always)]
+                fn test([input]: [&$t; 1]) -> Result<(), Self::Error<'_>> {
+                    let multiple = match input.checked_rem(N) {
+                        Some(remainder) => remainder == $t::ZERO,
+                        // Either `N` is zero (whose only multiple is zero)
+                        // or this is the minimum value divided by -1:
+                        None => N != $t::ZERO || *input == $t::ZERO,
+                    };
+                    if multiple {
+                        Ok(())
+                    } else {
+                        Err(NotMultipleOf(input))
+                    }
+                }
+            }
+
+            /// A term expected to be a multiple of a constant was not.
+            #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+            pub struct NotMultipleOf<'i, const N: $t>(&'i $t);
+
+            impl<const N: $t> fmt::Display for NotMultipleOf<'_, N> {
+                #[inline]
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let Self(z) = *self;
+                    write!(f, "{z:#?} is not a multiple of {N:#?}")
+                }
+            }
+
             /// Terms less than a constant (defined by `PartialOrd` comparison).
             pub type LessThan<const N: $t> = Sigma<$t, LessThanInvariant<N>>;
 
@@ -25,7 +145,7 @@ macro_rules! mk_mod {
             pub struct LessThanInvariant<const N: $t>;
 
             impl<const N: $t> Test<$t, 1> for LessThanInvariant<N> {
-                const ADJECTIVE: &str = "positive";
+                const ADJECTIVE: &str = "less than the bound";
                 type Error<'i>
                     = NotLessThan<'i, N>
                 where
@@ -41,7 +161,7 @@ macro_rules! mk_mod {
                 }
             }
 
-            /// A term expected to be positive was not.
+            /// A term expected to be less than a constant was not.
             #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
             pub struct NotLessThan<'i, const N: $t>(&'i $t);
 
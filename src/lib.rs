@@ -2,27 +2,57 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod aligned;
 mod all;
 mod all_pairs;
+mod angle;
+mod any;
+mod approx;
 
-#[cfg(all(not(feature = "std"), any(test, feature = "quickcheck")))]
+#[cfg(not(feature = "malachite"))]
+mod arithmetic;
+
+#[cfg(any(feature = "alloc", test, feature = "quickcheck"))]
 extern crate alloc;
 
+mod bits;
+mod brand;
+mod constant;
+mod count;
+mod encoded;
 mod finite;
+mod float;
+mod heap;
+mod in_bounds;
+mod interval;
 mod invariant;
+mod iter;
+mod len;
 mod less_than;
+mod linear;
+mod map;
 mod negative;
 mod non_negative;
 mod non_positive;
 mod non_zero;
+mod none_of;
+mod not_nan;
 mod on_unit;
 
 #[cfg(not(feature = "malachite"))]
 mod one;
 
+mod parent_child;
+mod parity;
+mod permutation;
 mod positive;
+mod power_of_two;
+mod probability;
 mod sigma;
+mod sigma_with;
 mod sorted;
+mod text;
+mod unique;
 
 #[cfg(test)]
 mod test;
@@ -31,23 +61,91 @@ mod test;
 mod zero;
 
 pub use {
+    aligned::{Aligned, AlignedInvariant, NotAligned},
     all::{All, NotAll},
     all_pairs::{AllPairs, NotAllPairs},
+    angle::{Degrees, OnSignedUnit, Radians, SignedDegrees, SignedRadians},
+    any::{Any, NotAny},
+    approx::{
+        ApproxEq, ApproxEqInvariant, ApproxOnUnit, ApproxOnUnitInvariant, NotApproxEq,
+        NotApproxOnUnit,
+    },
+    bits::Bits,
+    brand::{Branded, BrandedIndex, brand},
+    constant::{Const, ConstInt, ConstOne, ConstZero, Epsilons, MinusOne, MinusPi, Pi, Tau},
+    count::{Count, WrongCount},
+    encoded::{
+        Base64, Base64Alphabet, Base64Invariant, Hex, HexCase, HexInvariant, Lower, Mixed,
+        NotBase64, NotHex, Standard, Upper, UrlSafe, Utf8Bytes, Utf8BytesInvariant,
+    },
     finite::{CanBeInfinite, Finite, FiniteInvariant, NotFinite},
-    invariant::Test,
+    heap::{HeapPair, MaxHeap, MaxHeapInvariant, MinHeap, MinHeapInvariant},
+    in_bounds::{InBounds, InBoundsInvariant, NotInBounds},
+    interval::{Interval, IntervalInvariant, NotInInterval},
+    invariant::{Test, TestWith},
+    iter::{Checked, FilterValid, Rejected, SigmaIterExt, TryChecked},
+    len::{
+        AtLeast, AtMost, Exactly, InRange, Len, LenAtLeast, LenAtMost, LenInRange, LenInvariant,
+        Length, NotInRange, WrongLen,
+    },
     less_than::{i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize},
+    linear::{
+        Diagonal, DiagonalInvariant, NonZeroElement, NotOrthonormal, NotSymmetric, NotUnitNorm,
+        Orthonormal, OrthonormalInvariant, RowStochastic, RowStochasticInvariant, Symmetric,
+        SymmetricInvariant, UnitNorm, UnitNormInvariant, UpperTriangular, UpperTriangularInvariant,
+    },
+    map::{AllEntries, AllKeys, AllValues, Map, NotAllEntries, NotAllKeys, NotAllValues},
     negative::{Negative, NegativeInvariant, NotNegative},
     non_negative::{NonNegative, NonNegativeInvariant, NotNonNegative},
     non_positive::{NonPositive, NonPositiveInvariant, NotNonPositive},
-    non_zero::{NonZero, NonZeroInvariant, NotNonZero},
+    non_zero::{NoZeroDivisors, NonZero, NonZeroInvariant, NotNonZero},
+    none_of::{NoneOf, NotNone},
+    not_nan::{CanBeNaN, IsNaN, NotNaN, NotNaNInvariant, Total},
     on_unit::{NotOnUnit, OnUnit, OnUnitInvariant},
+    parent_child::{NotParentChild, ParentChild},
+    parity::{Even, EvenInvariant, NotEven, NotOdd, Odd, OddInvariant},
+    permutation::{NotPermutation, Permutation, PermutationInvariant},
     positive::{NotPositive, Positive, PositiveInvariant},
+    power_of_two::{NotPowerOfTwo, PowerOfTwo, PowerOfTwoInvariant},
+    probability::{
+        Distribution, DistributionInvariant, LogProbability, NotDistribution, Probability,
+    },
     sigma::Sigma,
-    sorted::{OutOfOrder, Sorted, SortedInvariant, SortedPair},
+    sigma_with::SigmaWith,
+    sorted::{
+        ApproxSorted, ApproxSortedInvariant, ApproxSortedPair, OutOfOrder, Sorted, SortedInvariant,
+        SortedPair,
+    },
+    text::{
+        Ascii, AsciiAlphanumeric, AsciiAlphanumericInvariant, AsciiInvariant, ControlChar,
+        EmptyStr, Identifier, IdentifierInvariant, Lowercase, LowercaseInvariant, Matches,
+        MatchesInvariant, MaxLen, MaxLenInvariant, Mismatch, NoControlChars,
+        NoControlCharsInvariant, NonEmptyStr, NonEmptyStrInvariant, NotAscii, NotAsciiAlphanumeric,
+        NotIdentifier, NotLowercase, NotTrimmed, Pattern, Text, TooLong, Trimmed, TrimmedInvariant,
+        valid_pattern,
+    },
+    unique::{ByEq, Duplicate, FindDuplicate, NotUnique, Unique, UniqueInvariant},
 };
 
+#[cfg(feature = "alloc")]
+pub use unique::ByOrd;
+
+#[cfg(feature = "std")]
+pub use unique::ByHash;
+
 #[cfg(feature = "malachite")]
-pub use malachite_base::num::basic::traits::{One, Zero};
+pub use malachite_base::num::{
+    arithmetic::traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub,
+    },
+    basic::traits::{One, Zero},
+};
 
 #[cfg(not(feature = "malachite"))]
-pub use {one::One, zero::Zero};
+pub use {
+    arithmetic::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatingAdd, SaturatingMul, SaturatingSub,
+    },
+    one::One,
+    zero::Zero,
+};
@@ -0,0 +1,464 @@
+//! Vectors and matrices as fixed-size arrays
+//! (`[Input; N]` and `[[Input; N]; M]`, i.e. `M` rows of `N` columns).
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{All, Const, DistributionInvariant, One, Sigma, Test, Zero},
+    core::{fmt, marker::PhantomData, ops},
+};
+
+#[cfg(feature = "std")]
+use crate::Finite;
+
+/// Dot product of two vectors.
+#[inline]
+fn dot<Input: Clone + Zero + ops::Add<Output = Input> + ops::Mul<Output = Input>>(
+    lhs: &[Input],
+    rhs: &[Input],
+) -> Input {
+    lhs.iter()
+        .zip(rhs)
+        .fold(Input::ZERO, |acc, (l, r)| acc.add(l.clone().mul(r.clone())))
+}
+
+/// Whether a term is within a given tolerance of a target.
+#[inline]
+fn within<Input: Clone + PartialOrd + ops::Add<Output = Input> + ops::Sub<Output = Input>>(
+    input: &Input,
+    target: Input,
+    tolerance: Input,
+) -> bool {
+    *input >= target.clone().sub(tolerance.clone()) && *input <= target.add(tolerance)
+}
+
+/// Vectors whose Euclidean norm is one,
+/// up to a given tolerance on the _squared_ norm
+/// (which avoids a square root, and is about twice the tolerance on the norm itself).
+pub type UnitNorm<Input, const N: usize, Tolerance> =
+    Sigma<[Input; N], UnitNormInvariant<Input, N, Tolerance>>;
+
+/// Vectors whose Euclidean norm is one,
+/// up to a given tolerance on the _squared_ norm
+/// (which avoids a square root, and is about twice the tolerance on the norm itself).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UnitNormInvariant<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Mul<Output = Input>
+        + ops::Sub<Output = Input>,
+    const N: usize,
+    Tolerance: Const<Input>,
+>(PhantomData<Input>, PhantomData<Tolerance>);
+
+impl<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Mul<Output = Input>
+        + ops::Sub<Output = Input>,
+    const N: usize,
+    Tolerance: Const<Input>,
+> Test<[Input; N], 1> for UnitNormInvariant<Input, N, Tolerance>
+{
+    const ADJECTIVE: &str = "of unit norm";
+
+    type Error<'i>
+        = NotUnitNorm<Input>
+    where
+        [Input; N]: 'i;
+
+    #[inline]
+    fn test([input]: [&[Input; N]; 1]) -> Result<(), Self::Error<'_>> {
+        let squared_norm = dot(input, input);
+        if within(&squared_norm, Input::ONE, Tolerance::VALUE) {
+            Ok(())
+        } else {
+            Err(NotUnitNorm {
+                squared_norm,
+                tolerance: Tolerance::VALUE,
+            })
+        }
+    }
+}
+
+/// A vector expected to have unit norm did not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotUnitNorm<Input: fmt::Debug> {
+    /// Squared Euclidean norm of the offending vector.
+    squared_norm: Input,
+    /// How near one the squared norm should have been.
+    tolerance: Input,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotUnitNorm<Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            ref squared_norm,
+            ref tolerance,
+        } = *self;
+        write!(
+            f,
+            "Squared norm {squared_norm:#?} is not within {tolerance:#?} of one",
+        )
+    }
+}
+
+/// Implement `normalize` for a primitive floating-point type.
+macro_rules! impl_normalize {
+    ($f:ident) => {
+        #[cfg(feature = "std")]
+        impl<const N: usize, Tolerance: Const<$f>> UnitNorm<$f, N, Tolerance> {
+            /// Scale a finite vector to unit norm,
+            /// or return `None` if every element is zero.
+            ///
+            /// Elements are first scaled by the largest magnitude,
+            /// so squaring them can neither overflow nor underflow to zero.
+            /// Rounding error in the result grows with `N`,
+            /// so `Tolerance` should be at least a few times `N` epsilons.
+            #[inline]
+            #[must_use]
+            pub fn normalize(vector: [Finite<$f>; N]) -> Option<Self> {
+                let scale = vector
+                    .iter()
+                    .fold($f::ZERO, |acc, element| acc.max(element.get().abs()));
+                if scale <= $f::ZERO {
+                    return None;
+                }
+                let scaled = vector.map(|element| element.get() / scale);
+                let norm = scaled
+                    .iter()
+                    .fold($f::ZERO, |acc, &element| element.mul_add(element, acc))
+                    .sqrt();
+                Some(Self::new(scaled.map(|element| element / norm)))
+            }
+        }
+    };
+}
+
+impl_normalize!(f32);
+impl_normalize!(f64);
+
+/// Matrices whose rows are orthonormal
+/// (each of unit norm and orthogonal to every other),
+/// up to a given tolerance on each pairwise dot product.
+///
+/// If the matrix is square, this makes it an orthogonal matrix,
+/// whose transpose is its inverse.
+pub type Orthonormal<Input, const M: usize, const N: usize, Tolerance> =
+    Sigma<[[Input; N]; M], OrthonormalInvariant<Input, M, N, Tolerance>>;
+
+/// Matrices whose rows are orthonormal
+/// (each of unit norm and orthogonal to every other),
+/// up to a given tolerance on each pairwise dot product.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OrthonormalInvariant<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Mul<Output = Input>
+        + ops::Sub<Output = Input>,
+    const M: usize,
+    const N: usize,
+    Tolerance: Const<Input>,
+>(PhantomData<Input>, PhantomData<Tolerance>);
+
+impl<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Mul<Output = Input>
+        + ops::Sub<Output = Input>,
+    const M: usize,
+    const N: usize,
+    Tolerance: Const<Input>,
+> Test<[[Input; N]; M], 1> for OrthonormalInvariant<Input, M, N, Tolerance>
+{
+    const ADJECTIVE: &str = "orthonormal";
+
+    type Error<'i>
+        = NotOrthonormal<Input>
+    where
+        [[Input; N]; M]: 'i;
+
+    #[inline]
+    fn test([input]: [&[[Input; N]; M]; 1]) -> Result<(), Self::Error<'_>> {
+        for (index_of_fst, fst) in input.iter().enumerate() {
+            for (index_of_snd, snd) in input.iter().enumerate().skip(index_of_fst) {
+                let product = dot(fst, snd);
+                let target = if index_of_fst == index_of_snd {
+                    Input::ONE
+                } else {
+                    Input::ZERO
+                };
+                if !within(&product, target, Tolerance::VALUE) {
+                    return Err(NotOrthonormal {
+                        dot: product,
+                        index_of_fst,
+                        index_of_snd,
+                        tolerance: Tolerance::VALUE,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A matrix expected to have orthonormal rows did not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotOrthonormal<Input: fmt::Debug> {
+    /// Dot product of the offending pair of rows.
+    dot: Input,
+    /// Index of the first row.
+    index_of_fst: usize,
+    /// Index of the second row (equal to the first if its norm was wrong).
+    index_of_snd: usize,
+    /// How near the expected value the dot product should have been.
+    tolerance: Input,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotOrthonormal<Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            ref dot,
+            index_of_fst,
+            index_of_snd,
+            ref tolerance,
+        } = *self;
+        write!(
+            f,
+            "Rows #{index_of_fst} and #{index_of_snd} have dot product {dot:#?}, \
+            not within {tolerance:#?} of {}",
+            if index_of_fst == index_of_snd {
+                "one"
+            } else {
+                "zero"
+            },
+        )
+    }
+}
+
+/// Square matrices equal to their own transpose.
+pub type Symmetric<Input, const N: usize> = Sigma<[[Input; N]; N], SymmetricInvariant<Input, N>>;
+
+/// Square matrices equal to their own transpose.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SymmetricInvariant<Input: PartialEq + fmt::Debug, const N: usize>(PhantomData<Input>);
+
+impl<Input: PartialEq + fmt::Debug, const N: usize> Test<[[Input; N]; N], 1>
+    for SymmetricInvariant<Input, N>
+{
+    const ADJECTIVE: &str = "symmetric";
+
+    type Error<'i>
+        = NotSymmetric<'i, Input>
+    where
+        [[Input; N]; N]: 'i;
+
+    #[inline]
+    fn test([input]: [&[[Input; N]; N]; 1]) -> Result<(), Self::Error<'_>> {
+        for (row, elements) in input.iter().enumerate() {
+            // Pair each element above the diagonal with the row holding its transpose:
+            for (column, (above, transposed_row)) in
+                elements.iter().zip(input).enumerate().skip(row)
+            {
+                if let Some(below) = transposed_row.get(row)
+                    && above != below
+                {
+                    return Err(NotSymmetric {
+                        above,
+                        below,
+                        column,
+                        row,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A matrix expected to be symmetric was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotSymmetric<'i, Input: fmt::Debug> {
+    /// Offending element above the diagonal.
+    above: &'i Input,
+    /// Element below the diagonal that should have been equal.
+    below: &'i Input,
+    /// Column of the element above the diagonal.
+    column: usize,
+    /// Row of the element above the diagonal.
+    row: usize,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotSymmetric<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            above,
+            below,
+            column,
+            row,
+        } = *self;
+        write!(
+            f,
+            "Element at ({row}, {column}) is {above:#?} \
+            but its transpose at ({column}, {row}) is {below:#?}",
+        )
+    }
+}
+
+/// Matrices whose only nonzero elements are on the main diagonal.
+pub type Diagonal<Input, const M: usize, const N: usize> =
+    Sigma<[[Input; N]; M], DiagonalInvariant<Input, M, N>>;
+
+/// Matrices whose only nonzero elements are on the main diagonal.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DiagonalInvariant<Input: PartialEq + Zero + fmt::Debug, const M: usize, const N: usize>(
+    PhantomData<Input>,
+);
+
+impl<Input: PartialEq + Zero + fmt::Debug, const M: usize, const N: usize> Test<[[Input; N]; M], 1>
+    for DiagonalInvariant<Input, M, N>
+{
+    const ADJECTIVE: &str = "diagonal";
+
+    type Error<'i>
+        = NonZeroElement<'i, Input>
+    where
+        [[Input; N]; M]: 'i;
+
+    #[inline]
+    fn test([input]: [&[[Input; N]; M]; 1]) -> Result<(), Self::Error<'_>> {
+        for (row, elements) in input.iter().enumerate() {
+            for (column, element) in elements.iter().enumerate() {
+                if column != row && *element != Input::ZERO {
+                    return Err(NonZeroElement {
+                        column,
+                        element,
+                        row,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Matrices whose only nonzero elements are on or above the main diagonal.
+pub type UpperTriangular<Input, const M: usize, const N: usize> =
+    Sigma<[[Input; N]; M], UpperTriangularInvariant<Input, M, N>>;
+
+/// Matrices whose only nonzero elements are on or above the main diagonal.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UpperTriangularInvariant<
+    Input: PartialEq + Zero + fmt::Debug,
+    const M: usize,
+    const N: usize,
+>(PhantomData<Input>);
+
+impl<Input: PartialEq + Zero + fmt::Debug, const M: usize, const N: usize> Test<[[Input; N]; M], 1>
+    for UpperTriangularInvariant<Input, M, N>
+{
+    const ADJECTIVE: &str = "upper-triangular";
+
+    type Error<'i>
+        = NonZeroElement<'i, Input>
+    where
+        [[Input; N]; M]: 'i;
+
+    #[inline]
+    fn test([input]: [&[[Input; N]; M]; 1]) -> Result<(), Self::Error<'_>> {
+        for (row, elements) in input.iter().enumerate() {
+            for (column, element) in elements.iter().enumerate().take(row) {
+                if *element != Input::ZERO {
+                    return Err(NonZeroElement {
+                        column,
+                        element,
+                        row,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An element of a matrix expected to be zero was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonZeroElement<'i, Input: fmt::Debug> {
+    /// Column of the offending element.
+    column: usize,
+    /// Offending element.
+    element: &'i Input,
+    /// Row of the offending element.
+    row: usize,
+}
+
+impl<Input: fmt::Debug> fmt::Display for NonZeroElement<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            column,
+            element,
+            row,
+        } = *self;
+        write!(
+            f,
+            "Element at ({row}, {column}) should be zero but is {element:#?}",
+        )
+    }
+}
+
+/// Matrices in which each row is a discrete probability distribution
+/// (up to a given tolerance on each row's sum).
+pub type RowStochastic<Input, const M: usize, const N: usize, Tolerance> =
+    Sigma<[[Input; N]; M], RowStochasticInvariant<Input, M, N, Tolerance>>;
+
+/// Matrices in which each row is a discrete probability distribution
+/// (up to a given tolerance on each row's sum).
+pub type RowStochasticInvariant<Input, const M: usize, const N: usize, Tolerance> =
+    All<DistributionInvariant<Input, N, Tolerance>, [[Input; N]; M]>;
@@ -0,0 +1,278 @@
+//! Key-value data structures in which each key, value, or entry satisfies a given invariant.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "alloc")]
+use alloc::{collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// Key-value data structure whose entries can be iterated by reference.
+pub trait Map {
+    /// Type of each key.
+    type Key: fmt::Debug;
+
+    /// Type of each value.
+    type Value: fmt::Debug;
+
+    /// Iterate over each entry as a pair of references.
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> Map for [(K, V)] {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter().map(|pair| (&pair.0, &pair.1))
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> Map for [(K, V); N] {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.as_slice().entries()
+    }
+}
+
+impl<M: Map + ?Sized> Map for &M {
+    type Key = M::Key;
+    type Value = M::Value;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        M::entries(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: fmt::Debug, V: fmt::Debug> Map for Vec<(K, V)> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.as_slice().entries()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: fmt::Debug, V: fmt::Debug> Map for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: fmt::Debug, V: fmt::Debug, S> Map for HashMap<K, V, S> {
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)> {
+        self.iter()
+    }
+}
+
+/// Key-value data structure in which each entry satisfies a given invariant.
+///
+/// The invariant sees each entry as a pair of references.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AllEntries<Invariant, Input: Map + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+)
+where
+    for<'e> Invariant: crate::Test<(&'e Input::Key, &'e Input::Value), 1>;
+
+impl<Invariant, Input: Map + fmt::Debug> crate::Test<Input, 1> for AllEntries<Invariant, Input>
+where
+    for<'e> Invariant: crate::Test<(&'e Input::Key, &'e Input::Value), 1>,
+{
+    const ADJECTIVE: &str = "valid at every entry";
+
+    type Error<'i>
+        = NotAllEntries<'i, Input::Key, Input::Value, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        for (key, value) in input.entries() {
+            if Invariant::test([&(key, value)]).is_err() {
+                return Err(NotAllEntries {
+                    key,
+                    phantom: PhantomData,
+                    value,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Key-value data structure in which each key satisfies a given invariant.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AllKeys<Invariant: crate::Test<Input::Key, 1>, Input: Map + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+);
+
+impl<Invariant: crate::Test<Input::Key, 1>, Input: Map + fmt::Debug> crate::Test<Input, 1>
+    for AllKeys<Invariant, Input>
+{
+    const ADJECTIVE: &str = "valid at every key";
+
+    type Error<'i>
+        = NotAllKeys<'i, Input::Key, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        for (key, _) in input.entries() {
+            match Invariant::test([key]) {
+                Ok(()) => {}
+                Err(error) => return Err(NotAllKeys { error, key }),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Key-value data structure in which each value satisfies a given invariant.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AllValues<Invariant: crate::Test<Input::Value, 1>, Input: Map + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+);
+
+impl<Invariant: crate::Test<Input::Value, 1>, Input: Map + fmt::Debug> crate::Test<Input, 1>
+    for AllValues<Invariant, Input>
+{
+    const ADJECTIVE: &str = "valid at every value";
+
+    type Error<'i>
+        = NotAllValues<'i, Input::Key, Input::Value, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        for (key, value) in input.entries() {
+            match Invariant::test([value]) {
+                Ok(()) => {}
+                Err(error) => return Err(NotAllValues { error, key, value }),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// At least one entry in a map did not satisfy the given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotAllEntries<'i, K: fmt::Debug, V: fmt::Debug, Invariant>
+where
+    for<'e> Invariant: crate::Test<(&'e K, &'e V), 1>,
+{
+    /// Key of the invalid entry.
+    key: &'i K,
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+    /// Value of the invalid entry.
+    value: &'i V,
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, Invariant> fmt::Display for NotAllEntries<'_, K, V, Invariant>
+where
+    for<'e> Invariant: crate::Test<(&'e K, &'e V), 1>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { key, value, .. } = *self;
+        write!(
+            f,
+            "Entry at key {key:#?} (with value {value:#?}) was not {}",
+            <Invariant as crate::Test<(&K, &V), 1>>::ADJECTIVE,
+        )?;
+        // The error borrows a temporary pair, so we can't store it;
+        // instead, re-run the (deterministic) test to display it:
+        match Invariant::test([&(key, value)]) {
+            Ok(()) => Ok(()),
+            Err(error) => write!(f, ": {error}"),
+        }
+    }
+}
+
+/// At least one key in a map did not satisfy the given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotAllKeys<'i, K: fmt::Debug, Invariant: crate::Test<K, 1>> {
+    /// Error indicating why this key wasn't valid.
+    error: Invariant::Error<'i>,
+    /// Invalid key.
+    key: &'i K,
+}
+
+impl<K: fmt::Debug, Invariant: crate::Test<K, 1>> fmt::Display for NotAllKeys<'_, K, Invariant> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { ref error, key } = *self;
+        write!(f, "Key {key:#?} was not {}: {error}", Invariant::ADJECTIVE)
+    }
+}
+
+/// At least one value in a map did not satisfy the given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotAllValues<'i, K: fmt::Debug, V: fmt::Debug, Invariant: crate::Test<V, 1>> {
+    /// Error indicating why this value wasn't valid.
+    error: Invariant::Error<'i>,
+    /// Key of the invalid value.
+    key: &'i K,
+    /// Invalid value.
+    value: &'i V,
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, Invariant: crate::Test<V, 1>> fmt::Display
+    for NotAllValues<'_, K, V, Invariant>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            ref error,
+            key,
+            value,
+        } = *self;
+        write!(
+            f,
+            "Value at key {key:#?} ({value:#?}) was not {}: {error}",
+            Invariant::ADJECTIVE,
+        )
+    }
+}
@@ -1,10 +1,27 @@
 //! Nonzero terms (defined by comparison to zero).
 
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
 use {
-    crate::{Sigma, Test, Zero},
-    core::{fmt, marker::PhantomData},
+    crate::{Positive, PositiveInvariant, Rejected, Sigma, Test, Zero},
+    core::{fmt, marker::PhantomData, num},
 };
 
+/// Types in which the product of two nonzero values is never zero,
+/// which makes `NonZero * NonZero` a `NonZero`.
+///
+/// No primitive type qualifies:
+/// floating-point products can underflow to zero,
+/// and fixed-width integer products can wrap to zero in release builds
+/// (e.g. `16_u8 * 16_u8`).
+/// Multiply those with `CheckedMul` instead, which returns `None` on overflow,
+/// or implement this for types that really have no zero divisors
+/// (e.g. arbitrary-precision integers).
+pub trait NoZeroDivisors {}
+
 /// Nonzero terms (defined by comparison to zero).
 pub type NonZero<Input> = Sigma<Input, NonZeroInvariant<Input>>;
 
@@ -45,3 +62,56 @@ impl<Input: fmt::Debug + PartialEq + Zero> fmt::Display for NotNonZero<'_, Input
         write!(f, "{z:#?} == {:#?}", Input::ZERO)
     }
 }
+
+/// Convert a sigma type to `core::num::NonZero`, which is niche-optimized
+/// (e.g. `Option<core::num::NonZero<u32>>` is the same size as `u32`).
+///
+/// Not `From`, since a value constructed without checking in a release build could be zero,
+/// and `core::num::NonZero` must never be.
+macro_rules! impl_into_core {
+    ($sigma:ident, $invariant:ident, $t:ident) => {
+        impl TryFrom<$sigma<$t>> for num::NonZero<$t> {
+            type Error = Rejected<$t, $invariant<$t>>;
+
+            #[inline]
+            fn try_from(sigma: $sigma<$t>) -> Result<Self, Self::Error> {
+                let raw = sigma.get();
+                Self::new(raw).ok_or(Rejected::new(raw))
+            }
+        }
+    };
+}
+
+/// Convert between `NonZero` and `core::num::NonZero`.
+macro_rules! impl_core {
+    ($t:ident) => {
+        impl From<num::NonZero<$t>> for NonZero<$t> {
+            #[inline]
+            fn from(non_zero: num::NonZero<$t>) -> Self {
+                Self::new(non_zero.get())
+            }
+        }
+
+        impl_into_core!(NonZero, NonZeroInvariant, $t);
+    };
+}
+
+impl_core!(i8);
+impl_core!(i16);
+impl_core!(i32);
+impl_core!(i64);
+impl_core!(i128);
+impl_core!(isize);
+impl_core!(u8);
+impl_core!(u16);
+impl_core!(u32);
+impl_core!(u64);
+impl_core!(u128);
+impl_core!(usize);
+
+impl_into_core!(Positive, PositiveInvariant, u8);
+impl_into_core!(Positive, PositiveInvariant, u16);
+impl_into_core!(Positive, PositiveInvariant, u32);
+impl_into_core!(Positive, PositiveInvariant, u64);
+impl_into_core!(Positive, PositiveInvariant, u128);
+impl_into_core!(Positive, PositiveInvariant, usize);
@@ -0,0 +1,73 @@
+//! Iterable data structure in which no element satisfies a given invariant.
+
+use core::{fmt, marker::PhantomData};
+
+/// Iterable data structure in which no element satisfies a given invariant.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NoneOf<Invariant: crate::Test<Input::Item, 1>, Input: IntoIterator + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+)
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>;
+
+impl<Invariant: crate::Test<Input::Item, 1>, Input: IntoIterator + fmt::Debug> crate::Test<Input, 1>
+    for NoneOf<Invariant, Input>
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    const ADJECTIVE: &str = "all invalid";
+
+    type Error<'i>
+        = NotNone<'i, Input::Item, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        for (index, element) in input.into_iter().enumerate() {
+            if Invariant::test([element]).is_ok() {
+                return Err(NotNone {
+                    element,
+                    index,
+                    phantom: PhantomData,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// At least one element in an iterator satisfied an invariant it was supposed to violate.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotNone<'i, Item: fmt::Debug, Invariant: crate::Test<Item, 1>> {
+    /// Valid element in the iterator.
+    element: &'i Item,
+    /// After how many other elements
+    /// did we see the this element?
+    index: usize,
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+}
+
+impl<Item: fmt::Debug, Invariant: crate::Test<Item, 1>> fmt::Display
+    for NotNone<'_, Item, Invariant>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { element, index, .. } = *self;
+        write!(
+            f,
+            "Element #{index} ({element:#?}) was {}",
+            Invariant::ADJECTIVE,
+        )
+    }
+}
@@ -0,0 +1,170 @@
+//! Values that are not `NaN` (but, unlike `Finite`, may be infinite),
+//! and a total order on floating-point values without `NaN`.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{Finite, Sigma, Test, Zero as _},
+    core::{
+        cmp::Ordering,
+        fmt,
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+        ops,
+    },
+};
+
+/// Types that can represent `NaN`.
+pub trait CanBeNaN {
+    /// Check that this value is not `NaN`.
+    fn check_not_nan(&self) -> bool;
+}
+
+/// A term expected not to be `NaN` was `NaN`.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IsNaN;
+
+impl fmt::Display for IsNaN {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`CanBeNaN::check_not_nan(..)` returned `false`")
+    }
+}
+
+/// Values that are not `NaN` (but, unlike `Finite`, may be infinite).
+pub type NotNaN<Input> = Sigma<Input, NotNaNInvariant<Input>>;
+
+/// Values that are not `NaN` (but, unlike `Finite`, may be infinite).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotNaNInvariant<Input: fmt::Debug + CanBeNaN>(PhantomData<Input>);
+
+impl<Input: fmt::Debug + CanBeNaN> Test<Input, 1> for NotNaNInvariant<Input> {
+    const ADJECTIVE: &str = "not NaN";
+    type Error<'i>
+        = IsNaN
+    where
+        Input: 'i;
+
+    #[inline(always)]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if input.check_not_nan() {
+            Ok(())
+        } else {
+            Err(IsNaN)
+        }
+    }
+}
+
+impl CanBeNaN for f32 {
+    #[inline(always)]
+    fn check_not_nan(&self) -> bool {
+        !self.is_nan()
+    }
+}
+
+impl CanBeNaN for f64 {
+    #[inline(always)]
+    fn check_not_nan(&self) -> bool {
+        !self.is_nan()
+    }
+}
+
+/// Floating-point sigma type (e.g. `NotNaN` or `Finite`) with a total order,
+/// e.g. to use as a `BTreeMap` key, to sort with `Ord`, or to hash.
+///
+/// `Sigma` can't implement `Eq`, `Ord`, or `Hash` for floats itself,
+/// since it already forwards them from any `Raw` that implements them,
+/// and the compiler can't rule out `core` one day implementing them for floats.
+///
+/// Since `NaN` is excluded, this order agrees with `PartialOrd`:
+/// in particular, `-0.0 == 0.0`, and the two hash identically.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Total<Float>(Float);
+
+impl<Float> Total<Float> {
+    /// Unwrap the underlying sigma type.
+    #[inline(always)]
+    pub fn get(self) -> Float {
+        self.0
+    }
+
+    /// Unwrap the underlying sigma type.
+    #[inline(always)]
+    pub const fn get_ref(&self) -> &Float {
+        &self.0
+    }
+
+    /// Totally order a floating-point sigma type.
+    #[inline(always)]
+    pub const fn new(float: Float) -> Self {
+        Self(float)
+    }
+}
+
+impl<Float> From<Float> for Total<Float> {
+    #[inline(always)]
+    fn from(float: Float) -> Self {
+        Self(float)
+    }
+}
+
+impl<Float> ops::Deref for Total<Float> {
+    type Target = Float;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Implement `Eq`, `Ord`, and `Hash` for a floating-point sigma type.
+macro_rules! impl_total {
+    ($sigma:ident, $f:ident) => {
+        impl Eq for Total<$sigma<$f>> {}
+
+        impl Hash for Total<$sigma<$f>> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                let raw = self.0.get();
+                // Negative zero is equal to zero, so it must hash identically:
+                let canonical = if raw == $f::ZERO { $f::ZERO } else { raw };
+                canonical.to_bits().hash(state);
+            }
+        }
+
+        impl Ord for Total<$sigma<$f>> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                // Only `NaN` is unordered, and the invariant excludes it:
+                self.0
+                    .get()
+                    .partial_cmp(&other.0.get())
+                    .unwrap_or(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq for Total<$sigma<$f>> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.cmp(other).is_eq()
+            }
+        }
+
+        impl PartialOrd for Total<$sigma<$f>> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+    };
+}
+
+impl_total!(Finite, f32);
+impl_total!(Finite, f64);
+impl_total!(NotNaN, f32);
+impl_total!(NotNaN, f64);
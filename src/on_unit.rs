@@ -2,8 +2,8 @@
 //! either inclusive or exclusive at each extreme.
 
 use {
-    crate::{One, Sigma, Test, Zero},
-    core::{cmp::Ordering, fmt, marker::PhantomData},
+    crate::{Const, One, Sigma, Test, Zero},
+    core::{cmp::Ordering, fmt, marker::PhantomData, ops},
 };
 
 /// Term expected to be on the unit interval (between 0 and 1) was not.
@@ -43,6 +43,36 @@ impl<
 pub type OnUnit<Input, const INCLUSIVE_AT_ZERO: bool, const INCLUSIVE_AT_ONE: bool> =
     Sigma<Input, OnUnitInvariant<Input, INCLUSIVE_AT_ZERO, INCLUSIVE_AT_ONE>>;
 
+impl<
+    Input: One + PartialOrd + Zero + fmt::Debug + ops::Add<Output = Input> + ops::Sub<Output = Input>,
+    const INCLUSIVE_AT_ZERO: bool,
+    const INCLUSIVE_AT_ONE: bool,
+> OnUnit<Input, INCLUSIVE_AT_ZERO, INCLUSIVE_AT_ONE>
+{
+    /// Create a new value on the unit interval,
+    /// first snapping a value just past an inclusive extreme
+    /// (by at most a given tolerance, e.g. `1.0000000002`) onto that extreme.
+    ///
+    /// Values past an exclusive extreme are not snapped,
+    /// since there is no single closest value on the interval.
+    /// # Panics
+    /// If the snapped value is not on the unit interval ***and*** debug assertions are enabled.
+    #[inline]
+    pub fn new_clamped<Tolerance: Const<Input>>(raw: Input) -> Self {
+        let snapped = if INCLUSIVE_AT_ZERO
+            && raw < Input::ZERO
+            && raw >= Input::ZERO.sub(Tolerance::VALUE)
+        {
+            Input::ZERO
+        } else if INCLUSIVE_AT_ONE && raw > Input::ONE && raw <= Input::ONE.add(Tolerance::VALUE) {
+            Input::ONE
+        } else {
+            raw
+        };
+        Self::new(snapped)
+    }
+}
+
 /// Terms on the unit interval (between 0 and 1),
 /// either inclusive or exclusive at each extreme.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
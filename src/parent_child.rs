@@ -0,0 +1,102 @@
+//! Complete binary tree stored in breadth-first order (e.g. a binary heap),
+//! in which each (parent, child) pair satisfies a given invariant.
+//!
+//! Element `i` is the parent of elements `2i + 1` and `2i + 2`.
+
+use core::{fmt, marker::PhantomData};
+
+/// Complete binary tree stored in breadth-first order (e.g. a binary heap),
+/// in which each (parent, child) pair satisfies a given invariant.
+///
+/// Element `i` is the parent of elements `2i + 1` and `2i + 2`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ParentChild<Invariant: crate::Test<Input::Item, 2>, Input: IntoIterator + fmt::Debug>(
+    PhantomData<Invariant>,
+    PhantomData<Input>,
+)
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>;
+
+impl<Invariant: crate::Test<Input::Item, 2>, Input: IntoIterator + fmt::Debug> crate::Test<Input, 1>
+    for ParentChild<Invariant, Input>
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    const ADJECTIVE: &str = "valid for each parent and child";
+
+    type Error<'i>
+        = NotParentChild<'i, Input::Item, Invariant>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        // Every parent has two children, so repeat each parent once
+        // and walk both in lockstep (without random access):
+        let parents = input
+            .into_iter()
+            .enumerate()
+            .flat_map(|indexed| [indexed, indexed]);
+        let children = input.into_iter().enumerate().skip(1);
+        for ((index_of_parent, parent), (index_of_child, child)) in parents.zip(children) {
+            match Invariant::test([parent, child]) {
+                Ok(()) => {}
+                Err(error) => {
+                    return Err(NotParentChild {
+                        child,
+                        error,
+                        index_of_child,
+                        index_of_parent,
+                        parent,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// At least one (parent, child) pair in a tree did not satisfy the given invariant.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotParentChild<'i, Item: fmt::Debug, Invariant: crate::Test<Item, 2>> {
+    /// Child in the pair.
+    child: &'i Item,
+    /// Error indicating why this pair wasn't valid.
+    error: Invariant::Error<'i>,
+    /// After how many other elements
+    /// did we see the child?
+    index_of_child: usize,
+    /// After how many other elements
+    /// did we see the parent?
+    index_of_parent: usize,
+    /// Parent in the pair.
+    parent: &'i Item,
+}
+
+impl<Item: fmt::Debug, Invariant: crate::Test<Item, 2>> fmt::Display
+    for NotParentChild<'_, Item, Invariant>
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            child,
+            ref error,
+            index_of_child,
+            index_of_parent,
+            parent,
+        } = *self;
+        write!(
+            f,
+            "Parent #{index_of_parent} ({parent:#?}) and child #{index_of_child} ({child:#?}) were not {}: {error}",
+            Invariant::ADJECTIVE,
+        )
+    }
+}
@@ -0,0 +1,90 @@
+//! Even and odd integers.
+
+use {
+    crate::{Bits, Sigma, Test},
+    core::{fmt, marker::PhantomData},
+};
+
+/// Even integers.
+pub type Even<Input> = Sigma<Input, EvenInvariant<Input>>;
+
+/// Even integers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EvenInvariant<Input: Bits + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Bits + fmt::Debug> Test<Input, 1> for EvenInvariant<Input> {
+    const ADJECTIVE: &str = "even";
+
+    type Error<'i>
+        = NotEven<'i, Input>
+    where
+        Input: 'i;
+
+    #[inline(always)]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if input.trailing_zeros() == 0 {
+            Err(NotEven(input))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// A term expected to be even was odd.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotEven<'i, Input: Bits + fmt::Debug>(&'i Input);
+
+impl<Input: Bits + fmt::Debug> fmt::Display for NotEven<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self(z) = *self;
+        write!(f, "{z:#?} is odd")
+    }
+}
+
+/// A term expected to be odd was even.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotOdd<'i, Input: Bits + fmt::Debug>(&'i Input);
+
+impl<Input: Bits + fmt::Debug> fmt::Display for NotOdd<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self(z) = *self;
+        write!(f, "{z:#?} is even")
+    }
+}
+
+/// Odd integers.
+pub type Odd<Input> = Sigma<Input, OddInvariant<Input>>;
+
+/// Odd integers.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct OddInvariant<Input: Bits + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Bits + fmt::Debug> Test<Input, 1> for OddInvariant<Input> {
+    const ADJECTIVE: &str = "odd";
+
+    type Error<'i>
+        = NotOdd<'i, Input>
+    where
+        Input: 'i;
+
+    #[inline(always)]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if input.trailing_zeros() == 0 {
+            Ok(())
+        } else {
+            Err(NotOdd(input))
+        }
+    }
+}
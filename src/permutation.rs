@@ -0,0 +1,254 @@
+//! Permutations of indices: every index less than the length appears exactly once.
+//!
+//! Applying, composing, or inverting a permutation indexes without bounds checks
+//! beyond those the invariant already made,
+//! so each panics only if a value was constructed without checking in a release build.
+
+use {
+    crate::{Sigma, Test},
+    core::{array, fmt, marker::PhantomData},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Permutations of indices: every index less than the length appears exactly once.
+///
+/// Applying a permutation `p` to a slice `s` produces `[s[p[0]], s[p[1]], ...]`.
+pub type Permutation<Input> = Sigma<Input, PermutationInvariant<Input>>;
+
+impl<Input: AsRef<[usize]> + fmt::Debug> Permutation<Input> {
+    /// Rearrange a slice, so that element `i` of the result is element `self[i]` of the slice.
+    /// # Panics
+    /// If the slice is not the same length as this permutation,
+    /// or if this is not a permutation, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn apply<T: Clone>(&self, slice: &[T]) -> Vec<T> {
+        #![expect(
+            clippy::indexing_slicing,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let indices = self.get_ref().as_ref();
+        assert_eq!(
+            indices.len(),
+            slice.len(),
+            "permutation and slice have different lengths",
+        );
+        indices.iter().map(|&i| slice[i].clone()).collect()
+    }
+}
+
+impl<const N: usize> Permutation<[usize; N]> {
+    /// Permutation that sorts an array (stably, i.e. equal elements keep their relative order).
+    #[inline]
+    #[must_use]
+    pub fn argsort<T: Ord>(array: &[T; N]) -> Self {
+        let mut indices = array::from_fn(|i| i);
+        indices.sort_unstable_by(|&lhs, &rhs| {
+            array
+                .get(lhs)
+                .cmp(&array.get(rhs))
+                .then_with(|| lhs.cmp(&rhs))
+        });
+        Self::new(indices)
+    }
+
+    /// Permutation equivalent to applying `first`, then `self`:
+    /// `self.compose(&first).apply(s) == self.apply(&first.apply(s))`.
+    /// # Panics
+    /// If either is not a permutation, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn compose(&self, first: &Self) -> Self {
+        #![expect(
+            clippy::indexing_slicing,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        Self::new(self.get().map(|i| first[i]))
+    }
+
+    /// Permutation that changes nothing.
+    #[inline]
+    #[must_use]
+    pub fn identity() -> Self {
+        Self::new(array::from_fn(|i| i))
+    }
+
+    /// Permutation that undoes this one:
+    /// `self.inverse().compose(self)` is the identity.
+    /// # Panics
+    /// If this is not a permutation, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        #![expect(
+            clippy::indexing_slicing,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let mut inverse = [0; N];
+        for (i, &p) in self.iter().enumerate() {
+            inverse[p] = i;
+        }
+        Self::new(inverse)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Permutation<Vec<usize>> {
+    /// Permutation that sorts a slice (stably, i.e. equal elements keep their relative order).
+    #[inline]
+    #[must_use]
+    pub fn argsort<T: Ord>(slice: &[T]) -> Self {
+        let mut indices: Vec<usize> = (0..slice.len()).collect();
+        indices.sort_by(|&lhs, &rhs| slice.get(lhs).cmp(&slice.get(rhs)));
+        Self::new(indices)
+    }
+
+    /// Permutation equivalent to applying `first`, then `self`:
+    /// `self.compose(&first).apply(s) == self.apply(&first.apply(s))`.
+    /// # Panics
+    /// If the permutations have different lengths,
+    /// or if either is not a permutation, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn compose(&self, first: &Self) -> Self {
+        Self::new(self.apply(first))
+    }
+
+    /// Permutation of a given length that changes nothing.
+    #[inline]
+    #[must_use]
+    pub fn identity(len: usize) -> Self {
+        Self::new((0..len).collect())
+    }
+
+    /// Permutation that undoes this one:
+    /// `self.inverse().compose(self)` is the identity.
+    /// # Panics
+    /// If this is not a permutation, which is possible only if
+    /// it was constructed without checking in a release build.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self {
+        #![expect(
+            clippy::indexing_slicing,
+            reason = "possible only if constructed without checking in a release build"
+        )]
+
+        let mut inverse = vec![0; self.len()];
+        for (i, &p) in self.iter().enumerate() {
+            inverse[p] = i;
+        }
+        Self::new(inverse)
+    }
+}
+
+/// Permutations of indices: every index less than the length appears exactly once.
+///
+/// Each index is checked against the length (as `usize::LessThan` would),
+/// then against every index before it (as `Unique` would):
+/// in quadratic time without `alloc`, or in linear time with it.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PermutationInvariant<Input: AsRef<[usize]> + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: AsRef<[usize]> + fmt::Debug> Test<Input, 1> for PermutationInvariant<Input> {
+    const ADJECTIVE: &str = "a permutation";
+
+    type Error<'i>
+        = NotPermutation<'i>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let indices = input.as_ref();
+        let len = indices.len();
+        #[cfg(feature = "alloc")]
+        let mut seen = vec![None; len];
+        for (index_of_snd, element) in indices.iter().enumerate() {
+            if *element >= len {
+                return Err(NotPermutation::OutOfBounds {
+                    element,
+                    index: index_of_snd,
+                    len,
+                });
+            }
+
+            #[cfg(feature = "alloc")]
+            let earlier = seen
+                .get_mut(*element)
+                .and_then(|slot| slot.replace(index_of_snd));
+            #[cfg(not(feature = "alloc"))]
+            let earlier = indices
+                .iter()
+                .take(index_of_snd)
+                .position(|other| other == element);
+
+            if let Some(index_of_fst) = earlier {
+                return Err(NotPermutation::Repeated {
+                    element,
+                    index_of_fst,
+                    index_of_snd,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A list of indices expected to be a permutation was not.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NotPermutation<'i> {
+    /// An index was not less than the length.
+    OutOfBounds {
+        /// Offending index.
+        element: &'i usize,
+        /// Position of the offending index.
+        index: usize,
+        /// Length of the list, which every index should have been less than.
+        len: usize,
+    },
+    /// An index appeared more than once.
+    Repeated {
+        /// Offending index.
+        element: &'i usize,
+        /// Position of its first appearance.
+        index_of_fst: usize,
+        /// Position of its second appearance.
+        index_of_snd: usize,
+    },
+}
+
+impl fmt::Display for NotPermutation<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::OutOfBounds {
+                element,
+                index,
+                len,
+            } => write!(
+                f,
+                "Element #{index} ({element}) is not less than the length ({len})",
+            ),
+            Self::Repeated {
+                element,
+                index_of_fst,
+                index_of_snd,
+            } => write!(
+                f,
+                "Elements #{index_of_fst} and #{index_of_snd} are both {element}",
+            ),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Integers that are (positive) powers of two.
+
+use {
+    crate::{Bits, Sigma, Test},
+    core::{fmt, marker::PhantomData},
+};
+
+/// A term expected to be a power of two was not.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotPowerOfTwo<'i, Input: Bits + fmt::Debug>(&'i Input);
+
+impl<Input: Bits + fmt::Debug> fmt::Display for NotPowerOfTwo<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self(z) = *self;
+        write!(f, "{z:#?} is not a power of two")
+    }
+}
+
+/// Integers that are (positive) powers of two.
+pub type PowerOfTwo<Input> = Sigma<Input, PowerOfTwoInvariant<Input>>;
+
+/// Integers that are (positive) powers of two.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct PowerOfTwoInvariant<Input: Bits + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Bits + fmt::Debug> Test<Input, 1> for PowerOfTwoInvariant<Input> {
+    const ADJECTIVE: &str = "a power of two";
+
+    type Error<'i>
+        = NotPowerOfTwo<'i, Input>
+    where
+        Input: 'i;
+
+    #[inline(always)]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if input.check_power_of_two() {
+            Ok(())
+        } else {
+            Err(NotPowerOfTwo(input))
+        }
+    }
+}
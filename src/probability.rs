@@ -0,0 +1,179 @@
+//! Probabilities, log-probabilities, and discrete probability distributions.
+//!
+//! Independent probabilities multiply (and complement) as `OnUnit` values already do,
+//! and log-probabilities add as `NonPositive` values already do;
+//! this module adds odds and conversions between the two.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{Const, NonNegative, NonPositive, OnUnit, One, Positive, Sigma, Test, Zero},
+    core::{cmp::Ordering, fmt, marker::PhantomData, ops},
+};
+
+#[cfg(feature = "std")]
+use crate::NotNaN;
+
+/// Probabilities, i.e. terms on the closed unit interval `[0, 1]`.
+pub type Probability<Input> = OnUnit<Input, true, true>;
+
+/// Natural logarithms of probabilities, i.e. non-positive terms (including negative infinity).
+pub type LogProbability<Input> = NonPositive<Input>;
+
+/// Implement each method for a primitive floating-point type.
+macro_rules! impl_probability {
+    ($f:ident) => {
+        impl Probability<$f> {
+            /// Odds in favor (`p / (1 - p)`), which are infinite if `p` is one.
+            #[inline]
+            #[must_use]
+            pub fn odds(self) -> NonNegative<$f> {
+                self.map(|p| p / ($f::ONE - p))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl Probability<$f> {
+            /// Natural logarithm, which is negative infinity if `p` is zero.
+            #[inline]
+            #[must_use]
+            pub fn proven_ln(self) -> LogProbability<$f> {
+                self.map($f::ln)
+            }
+
+            /// Natural logarithm of the odds in favor,
+            /// which is infinite (of either sign) if `p` is zero or one.
+            #[inline]
+            #[must_use]
+            pub fn log_odds(self) -> NotNaN<$f> {
+                self.map(|p| p.ln() - (-p).ln_1p())
+            }
+        }
+
+        impl OnUnit<$f, false, true> {
+            /// Odds in favor (`p / (1 - p)`), which are infinite if `p` is one.
+            ///
+            /// Unlike `Probability::odds`, these are never zero.
+            #[inline]
+            #[must_use]
+            pub fn odds(self) -> Positive<$f> {
+                self.map(|p| p / ($f::ONE - p))
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl LogProbability<$f> {
+            /// Probability with this natural logarithm.
+            #[inline]
+            #[must_use]
+            pub fn proven_exp(self) -> Probability<$f> {
+                self.map($f::exp)
+            }
+        }
+    };
+}
+
+impl_probability!(f32);
+impl_probability!(f64);
+
+/// Discrete probability distributions over `N` outcomes:
+/// each element is a probability, and all elements sum to one
+/// (up to a given tolerance).
+pub type Distribution<Input, const N: usize, Tolerance> =
+    Sigma<[Input; N], DistributionInvariant<Input, N, Tolerance>>;
+
+/// Discrete probability distributions over `N` outcomes:
+/// each element is a probability, and all elements sum to one
+/// (up to a given tolerance).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DistributionInvariant<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Sub<Output = Input>,
+    const N: usize,
+    Tolerance: Const<Input>,
+>(PhantomData<Input>, PhantomData<Tolerance>);
+
+impl<
+    Input: Clone
+        + One
+        + PartialOrd
+        + Zero
+        + fmt::Debug
+        + ops::Add<Output = Input>
+        + ops::Sub<Output = Input>,
+    const N: usize,
+    Tolerance: Const<Input>,
+> Test<[Input; N], 1> for DistributionInvariant<Input, N, Tolerance>
+{
+    const ADJECTIVE: &str = "a probability distribution";
+
+    type Error<'i>
+        = NotDistribution<'i, Input>
+    where
+        [Input; N]: 'i;
+
+    #[inline]
+    fn test([input]: [&[Input; N]; 1]) -> Result<(), Self::Error<'_>> {
+        let mut sum = Input::ZERO;
+        for (index, element) in input.iter().enumerate() {
+            match (
+                element.partial_cmp(&Input::ZERO),
+                element.partial_cmp(&Input::ONE),
+            ) {
+                (
+                    Some(Ordering::Greater | Ordering::Equal),
+                    Some(Ordering::Less | Ordering::Equal),
+                ) => {}
+                _ => return Err(NotDistribution::NotProbability { element, index }),
+            }
+            sum = sum.add(element.clone());
+        }
+        let lo = Input::ONE.sub(Tolerance::VALUE);
+        let hi = Input::ONE.add(Tolerance::VALUE);
+        if lo <= sum && sum <= hi {
+            Ok(())
+        } else {
+            Err(NotDistribution::WrongSum(sum))
+        }
+    }
+}
+
+/// Array that was not a discrete probability distribution.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NotDistribution<'i, Input: fmt::Debug> {
+    /// An element that was not a probability.
+    NotProbability {
+        /// Offending element.
+        element: &'i Input,
+        /// Index of the offending element.
+        index: usize,
+    },
+    /// Elements that were each probabilities but did not sum to one.
+    WrongSum(Input),
+}
+
+impl<Input: fmt::Debug> fmt::Display for NotDistribution<'_, Input> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        match *self {
+            Self::NotProbability { element, index } => {
+                write!(f, "Element #{index} ({element:#?}) is not on [0, 1]")
+            }
+            Self::WrongSum(ref sum) => write!(f, "Sums to {sum:#?}, not one"),
+        }
+    }
+}
@@ -1,13 +1,10 @@
 //! Type that maintains a given invariant.
 
-#![expect(
-    clippy::arbitrary_source_item_ordering,
-    reason = "macros must be defined before they're used"
-)]
-
 use {
     crate::{
-        CanBeInfinite, Finite, Negative, NonNegative, NonPositive, NonZero, OnUnit, One, Positive,
+        Aligned, Bits, CanBeInfinite, CanBeNaN, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub,
+        Even, Finite, Negative, NoZeroDivisors, NonNegative, NonPositive, NonZero, NotNaN, Odd,
+        OnUnit, One, Positive, PowerOfTwo, Rejected, SaturatingAdd, SaturatingMul, SaturatingSub,
         Zero,
     },
     core::{
@@ -16,7 +13,7 @@ use {
         fmt,
         hash::{Hash, Hasher},
         marker::PhantomData,
-        ops,
+        mem, ops,
     },
 };
 
@@ -32,6 +29,9 @@ use quickcheck::{Arbitrary, Gen};
 #[cfg(all(any(test, feature = "quickcheck"), not(feature = "std")))]
 use alloc::boxed::Box;
 
+#[cfg(feature = "alloc")]
+use {alloc::vec::Vec, core::mem::ManuallyDrop};
+
 /// Implement a unary operation.
 macro_rules! impl_op_1 {
     ($op:ident, $fn:ident, $lhs:ident, $out:ident $(, $($bound:ident),* $(,)?)?) => {
@@ -252,6 +252,183 @@ macro_rules! impl_op_assign {
     };
 }
 
+/// Implement checked and saturating variants of a binary operation.
+macro_rules! impl_op_checked {
+    (
+        $checked:ident,
+        $checked_fn:ident,
+        $saturating:ident,
+        $saturating_fn:ident,
+        $lhs:ident,
+        $rhs:ident,
+        $out:ident
+        $(, $($bound:ident),* $(,)?)?
+    ) => {
+        impl<
+            L: $($($bound +)*)? fmt::Debug + $checked<R, Output: $($($bound +)*)? fmt::Debug>,
+            R: $($($bound +)*)? fmt::Debug,
+        > $checked<$rhs<R>> for $lhs<L>
+        {
+            type Output = $out<<L as $checked<R>>::Output>;
+
+            #[inline]
+            fn $checked_fn(self, rhs: $rhs<R>) -> Option<Self::Output> {
+                self.get().$checked_fn(rhs.get()).map(Sigma::new)
+            }
+        }
+
+        impl<
+            L: $($($bound +)*)? fmt::Debug + $saturating<R, Output: $($($bound +)*)? fmt::Debug>,
+            R: $($($bound +)*)? fmt::Debug,
+        > $saturating<$rhs<R>> for $lhs<L>
+        {
+            type Output = $out<<L as $saturating<R>>::Output>;
+
+            #[inline]
+            fn $saturating_fn(self, rhs: $rhs<R>) -> Self::Output {
+                self.map(|lhs| lhs.$saturating_fn(rhs.get()))
+            }
+        }
+
+        #[cfg(test)]
+        paste! {
+            #[cfg(test)]
+            #[expect(trivial_casts, clippy::as_conversions, reason = "must be an implementation detail of `quickcheck`")]
+            mod [< $lhs:snake _ $checked_fn _ $rhs:snake >] {
+                use super::*;
+
+                #[quickcheck]
+                fn doesnt_panic(lhs: $lhs</* L */ i64>, rhs: $rhs</* R */ i64>) {
+                    _ = <$lhs</* L */ i64> as $checked<$rhs</* R */ i64>>>::$checked_fn(lhs, rhs);
+                }
+            }
+
+            #[cfg(test)]
+            #[expect(trivial_casts, clippy::as_conversions, reason = "must be an implementation detail of `quickcheck`")]
+            mod [< $lhs:snake _ $saturating_fn _ $rhs:snake >] {
+                use super::*;
+
+                #[quickcheck]
+                fn doesnt_panic(lhs: $lhs</* L */ i64>, rhs: $rhs</* R */ i64>) {
+                    _ = <$lhs</* L */ i64> as $saturating<$rhs</* R */ i64>>>::$saturating_fn(lhs, rhs);
+                }
+            }
+        }
+    };
+}
+
+/// Checked and saturating addition.
+macro_rules! impl_checked_add {
+    ($lhs:ident, $rhs:ident, $out:ident $(, $($bound:ident),* $(,)?)?) => {
+        impl_op_checked!(CheckedAdd, checked_add, SaturatingAdd, saturating_add, $lhs, $rhs, $out $(, $($bound,)*)?);
+    };
+}
+
+/// Checked and saturating subtraction.
+macro_rules! impl_checked_sub {
+    ($lhs:ident, $rhs:ident, $out:ident $(, $($bound:ident),* $(,)?)?) => {
+        impl_op_checked!(CheckedSub, checked_sub, SaturatingSub, saturating_sub, $lhs, $rhs, $out $(, $($bound,)*)?);
+    };
+}
+
+/// Checked and saturating multiplication.
+macro_rules! impl_checked_mul {
+    ($lhs:ident, $rhs:ident, $out:ident $(, $($bound:ident),* $(,)?)?) => {
+        impl_op_checked!(CheckedMul, checked_mul, SaturatingMul, saturating_mul, $lhs, $rhs, $out $(, $($bound,)*)?);
+    };
+}
+
+/// Implement a binary operation on finite values
+/// that returns `None` instead of leaving the set of finite values.
+macro_rules! impl_finite_checked {
+    ($op:ident, $fn:ident, $checked:ident, $checked_fn:ident) => {
+        impl<
+            L: CanBeInfinite + fmt::Debug + ops::$op<R, Output: CanBeInfinite + fmt::Debug>,
+            R: CanBeInfinite + fmt::Debug,
+        > $checked<Finite<R>> for Finite<L>
+        {
+            type Output = Finite<<L as ops::$op<R>>::Output>;
+
+            #[inline]
+            fn $checked_fn(self, rhs: Finite<R>) -> Option<Self::Output> {
+                Sigma::try_new(self.get().$fn(rhs.get())).ok()
+            }
+        }
+
+        #[cfg(test)]
+        paste! {
+            #[cfg(test)]
+            #[expect(clippy::as_conversions, reason = "must be an implementation detail of `quickcheck`")]
+            mod [< finite_ $checked_fn _finite >] {
+                use super::*;
+
+                #[quickcheck]
+                fn none_iff_not_finite(lhs: Finite<f64>, rhs: Finite<f64>) -> bool {
+                    let raw = <f64 as ops::$op>::$fn(lhs.get(), rhs.get());
+                    lhs.$checked_fn(rhs).is_some() == raw.is_finite()
+                }
+            }
+        }
+    };
+}
+
+/// Multiply values on the unit interval:
+/// the product is inclusive at one iff both factors are,
+/// but it might underflow to zero (even if neither factor was zero).
+macro_rules! impl_on_unit_mul {
+    ($lhs_one:literal, $rhs_one:literal, $out_one:literal) => {
+        impl<
+            T: One + PartialOrd + Zero + fmt::Debug + ops::Mul<Output = T>,
+            const LHS_ZERO: bool,
+            const RHS_ZERO: bool,
+        > ops::Mul<OnUnit<T, RHS_ZERO, $rhs_one>> for OnUnit<T, LHS_ZERO, $lhs_one>
+        {
+            type Output = OnUnit<T, true, $out_one>;
+
+            #[inline]
+            fn mul(self, rhs: OnUnit<T, RHS_ZERO, $rhs_one>) -> Self::Output {
+                self.map(|lhs| lhs.mul(rhs.get()))
+            }
+        }
+    };
+}
+
+/// Implement a binary operation on integers
+/// that preserves a bit-level invariant (e.g. parity).
+macro_rules! impl_bits_op {
+    ($op:ident, $fn:ident, $lhs:ident, $rhs:ident, $out:ident) => {
+        impl<L: Bits + fmt::Debug + ops::$op<R, Output: Bits + fmt::Debug>, R: Bits + fmt::Debug>
+            ops::$op<$rhs<R>> for $lhs<L>
+        {
+            type Output = $out<<L as ops::$op<R>>::Output>;
+
+            #[inline]
+            fn $fn(self, rhs: $rhs<R>) -> Self::Output {
+                self.map(|lhs| lhs.$fn(rhs.get()))
+            }
+        }
+    };
+}
+
+/// Add or subtract integers aligned to the same power of two.
+macro_rules! impl_aligned_op {
+    ($op:ident, $fn:ident) => {
+        impl<
+            L: Bits + fmt::Debug + ops::$op<R, Output: Bits + fmt::Debug>,
+            R: Bits + fmt::Debug,
+            const ALIGN: usize,
+        > ops::$op<Aligned<R, ALIGN>> for Aligned<L, ALIGN>
+        {
+            type Output = Aligned<<L as ops::$op<R>>::Output, ALIGN>;
+
+            #[inline]
+            fn $fn(self, rhs: Aligned<R, ALIGN>) -> Self::Output {
+                self.map(|lhs| lhs.$fn(rhs.get()))
+            }
+        }
+    };
+}
+
 /// Addition only (and -assignment).
 macro_rules! impl_add {
     ($lhs:ident, $rhs:ident, $out:ident $(, $($bound:ident),* $(,)?)?) => {
@@ -284,6 +461,16 @@ macro_rules! impl_mul_assign {
     };
 }
 
+impl_aligned_op!(Add, add);
+impl_aligned_op!(Sub, sub);
+
+impl_bits_op!(Add, add, Even, Even, Even);
+impl_bits_op!(Add, add, Even, Odd, Odd);
+impl_bits_op!(Mul, mul, Even, Even, Even);
+impl_bits_op!(Mul, mul, Even, Odd, Even);
+impl_bits_op!(Sub, sub, Even, Even, Even);
+impl_bits_op!(Sub, sub, Even, Odd, Odd);
+
 impl<Z: CanBeInfinite + One + fmt::Debug> One for Finite<Z> {
     const ONE: Self = Self {
         phantom: PhantomData,
@@ -298,10 +485,10 @@ impl<Z: CanBeInfinite + Zero + fmt::Debug> Zero for Finite<Z> {
     };
 }
 
-impl_add!(Finite, Finite, Finite, CanBeInfinite);
-impl_sub!(Finite, Finite, Finite, CanBeInfinite);
-impl_mul!(Finite, Finite, Finite, CanBeInfinite);
-impl_mul_assign!(Finite, Finite, CanBeInfinite);
+impl_finite_checked!(Add, add, CheckedAdd, checked_add);
+impl_finite_checked!(Div, div, CheckedDiv, checked_div);
+impl_finite_checked!(Mul, mul, CheckedMul, checked_mul);
+impl_finite_checked!(Sub, sub, CheckedSub, checked_sub);
 impl_op_1!(Neg, neg, Finite, Finite, CanBeInfinite);
 
 impl_add!(Negative, Negative, Negative, PartialOrd, Zero);
@@ -314,6 +501,14 @@ impl_mul!(Negative, NonPositive, NonNegative, PartialOrd, Zero);
 impl_mul!(Negative, Positive, Negative, PartialOrd, Zero);
 impl_mul_assign!(Negative, Positive, PartialOrd, Zero);
 impl_op_1!(Neg, neg, Negative, Positive, PartialOrd, Zero);
+impl_checked_add!(Negative, Negative, Negative, PartialOrd, Zero);
+impl_checked_sub!(Negative, NonNegative, Negative, PartialOrd, Zero);
+impl_checked_add!(Negative, NonPositive, Negative, PartialOrd, Zero);
+impl_checked_sub!(Negative, Positive, Negative, PartialOrd, Zero);
+impl_checked_mul!(Negative, Negative, Positive, PartialOrd, Zero);
+impl_checked_mul!(Negative, NonNegative, NonPositive, PartialOrd, Zero);
+impl_checked_mul!(Negative, NonPositive, NonNegative, PartialOrd, Zero);
+impl_checked_mul!(Negative, Positive, Negative, PartialOrd, Zero);
 
 impl<T: One + PartialOrd + Zero + fmt::Debug> One for NonNegative<T> {
     const ONE: Self = Self {
@@ -340,6 +535,14 @@ impl_mul!(NonNegative, Positive, NonNegative, PartialOrd, Zero);
 impl_mul_assign!(NonNegative, NonNegative, PartialOrd, Zero);
 impl_mul_assign!(NonNegative, Positive, PartialOrd, Zero);
 impl_op_1!(Neg, neg, NonNegative, NonPositive, PartialOrd, Zero);
+impl_checked_sub!(NonNegative, Negative, Positive, PartialOrd, Zero);
+impl_checked_add!(NonNegative, NonNegative, NonNegative, PartialOrd, Zero);
+impl_checked_sub!(NonNegative, NonPositive, NonNegative, PartialOrd, Zero);
+impl_checked_add!(NonNegative, Positive, Positive, PartialOrd, Zero);
+impl_checked_mul!(NonNegative, Negative, NonPositive, PartialOrd, Zero);
+impl_checked_mul!(NonNegative, NonNegative, NonNegative, PartialOrd, Zero);
+impl_checked_mul!(NonNegative, NonPositive, NonPositive, PartialOrd, Zero);
+impl_checked_mul!(NonNegative, Positive, NonNegative, PartialOrd, Zero);
 
 impl_add!(NonPositive, Negative, Negative, PartialOrd, Zero);
 impl_sub!(NonPositive, NonNegative, NonPositive, PartialOrd, Zero);
@@ -352,9 +555,72 @@ impl_mul!(NonPositive, Positive, NonPositive, PartialOrd, Zero);
 impl_mul_assign!(NonPositive, NonNegative, PartialOrd, Zero);
 impl_mul_assign!(NonPositive, Positive, PartialOrd, Zero);
 impl_op_1!(Neg, neg, NonPositive, NonNegative, PartialOrd, Zero);
+impl_checked_add!(NonPositive, Negative, Negative, PartialOrd, Zero);
+impl_checked_sub!(NonPositive, NonNegative, NonPositive, PartialOrd, Zero);
+impl_checked_add!(NonPositive, NonPositive, NonPositive, PartialOrd, Zero);
+impl_checked_sub!(NonPositive, Positive, Negative, PartialOrd, Zero);
+impl_checked_mul!(NonPositive, Negative, NonNegative, PartialOrd, Zero);
+impl_checked_mul!(NonPositive, NonNegative, NonPositive, PartialOrd, Zero);
+impl_checked_mul!(NonPositive, NonPositive, NonNegative, PartialOrd, Zero);
+impl_checked_mul!(NonPositive, Positive, NonPositive, PartialOrd, Zero);
+
+impl<
+    L: NoZeroDivisors
+        + PartialEq
+        + Zero
+        + fmt::Debug
+        + ops::Mul<R, Output: PartialEq + Zero + fmt::Debug>,
+    R: NoZeroDivisors + PartialEq + Zero + fmt::Debug,
+> ops::Mul<NonZero<R>> for NonZero<L>
+{
+    type Output = NonZero<<L as ops::Mul<R>>::Output>;
+
+    #[inline]
+    fn mul(self, rhs: NonZero<R>) -> Self::Output {
+        self.map(|lhs| lhs.mul(rhs.get()))
+    }
+}
+
+impl<
+    'rhs,
+    L: NoZeroDivisors
+        + PartialEq
+        + Zero
+        + fmt::Debug
+        + ops::Mul<&'rhs R, Output: PartialEq + Zero + fmt::Debug>,
+    R: NoZeroDivisors + PartialEq + Zero + fmt::Debug,
+> ops::Mul<&'rhs NonZero<R>> for NonZero<L>
+{
+    type Output = NonZero<<L as ops::Mul<&'rhs R>>::Output>;
+
+    #[inline]
+    fn mul(self, rhs: &'rhs NonZero<R>) -> Self::Output {
+        self.map(|lhs| lhs.mul(rhs.get_ref()))
+    }
+}
+
+impl<
+    L: NoZeroDivisors + PartialEq + Zero + fmt::Debug + ops::MulAssign<R>,
+    R: NoZeroDivisors + PartialEq + Zero + fmt::Debug,
+> ops::MulAssign<NonZero<R>> for NonZero<L>
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: NonZero<R>) {
+        self.map_mut(|lhs| lhs.mul_assign(rhs.get()));
+    }
+}
 
-impl_mul!(NonZero, NonZero, NonZero, PartialEq, Zero);
 impl_op_1!(Neg, neg, NonZero, NonZero, PartialEq, Zero);
+impl_checked_mul!(NonZero, NonZero, NonZero, PartialEq, Zero);
+
+impl_op_1!(Neg, neg, NotNaN, NotNaN, CanBeNaN);
+
+impl_bits_op!(Add, add, Odd, Even, Odd);
+impl_bits_op!(Add, add, Odd, Odd, Even);
+impl_bits_op!(Mul, mul, Odd, Even, Even);
+impl_bits_op!(Mul, mul, Odd, Odd, Odd);
+impl_bits_op!(Sub, sub, Odd, Even, Odd);
+impl_bits_op!(Sub, sub, Odd, Odd, Even);
 
 impl<T: One + PartialOrd + Zero + fmt::Debug, const INCLUSIVE_AT_ZERO: bool> One
     for OnUnit<T, INCLUSIVE_AT_ZERO, true>
@@ -374,6 +640,28 @@ impl<Z: One + PartialOrd + Zero + fmt::Debug, const INCLUSIVE_AT_ONE: bool> Zero
     };
 }
 
+impl<
+    T: One + PartialOrd + Zero + fmt::Debug + ops::Sub<Output = T>,
+    const INCLUSIVE_AT_ZERO: bool,
+    const INCLUSIVE_AT_ONE: bool,
+> OnUnit<T, INCLUSIVE_AT_ZERO, INCLUSIVE_AT_ONE>
+{
+    /// One minus this value.
+    ///
+    /// The result is inclusive at zero iff this value was inclusive at one,
+    /// but it is always inclusive at one, since (e.g. for floating-point types)
+    /// one minus a tiny positive value can round to exactly one.
+    #[inline]
+    pub fn complement(self) -> OnUnit<T, INCLUSIVE_AT_ONE, true> {
+        self.map(|u| T::ONE.sub(u))
+    }
+}
+
+impl_on_unit_mul!(false, false, false);
+impl_on_unit_mul!(false, true, false);
+impl_on_unit_mul!(true, false, false);
+impl_on_unit_mul!(true, true, true);
+
 impl_sub!(Positive, Negative, Positive, PartialOrd, Zero);
 impl_add!(Positive, NonNegative, Positive, PartialOrd, Zero);
 impl_sub!(Positive, NonPositive, Positive, PartialOrd, Zero);
@@ -384,6 +672,14 @@ impl_mul!(Positive, NonPositive, NonPositive, PartialOrd, Zero);
 impl_mul!(Positive, Positive, Positive, PartialOrd, Zero);
 impl_mul_assign!(Positive, Positive, PartialOrd, Zero);
 impl_op_1!(Neg, neg, Positive, Negative, PartialOrd, Zero);
+impl_checked_sub!(Positive, Negative, Positive, PartialOrd, Zero);
+impl_checked_add!(Positive, NonNegative, Positive, PartialOrd, Zero);
+impl_checked_sub!(Positive, NonPositive, Positive, PartialOrd, Zero);
+impl_checked_add!(Positive, Positive, Positive, PartialOrd, Zero);
+impl_checked_mul!(Positive, Negative, Negative, PartialOrd, Zero);
+impl_checked_mul!(Positive, NonNegative, NonNegative, PartialOrd, Zero);
+impl_checked_mul!(Positive, NonPositive, NonPositive, PartialOrd, Zero);
+impl_checked_mul!(Positive, Positive, Positive, PartialOrd, Zero);
 
 impl<T: One + PartialOrd + Zero + fmt::Debug> One for Positive<T> {
     const ONE: Self = Self {
@@ -392,6 +688,26 @@ impl<T: One + PartialOrd + Zero + fmt::Debug> One for Positive<T> {
     };
 }
 
+impl_bits_op!(Mul, mul, PowerOfTwo, PowerOfTwo, PowerOfTwo);
+
+impl<L: Bits + fmt::Debug + CheckedMul<R, Output: Bits + fmt::Debug>, R: Bits + fmt::Debug>
+    CheckedMul<PowerOfTwo<R>> for PowerOfTwo<L>
+{
+    type Output = PowerOfTwo<<L as CheckedMul<R>>::Output>;
+
+    #[inline]
+    fn checked_mul(self, rhs: PowerOfTwo<R>) -> Option<Self::Output> {
+        self.get().checked_mul(rhs.get()).map(Sigma::new)
+    }
+}
+
+impl<T: Bits + One + fmt::Debug> One for PowerOfTwo<T> {
+    const ONE: Self = Self {
+        phantom: PhantomData,
+        raw: T::ONE,
+    };
+}
+
 impl<Raw: fmt::Debug, Invariant: crate::Test<Raw, 1>> From<Raw> for Sigma<Raw, Invariant> {
     #[inline(always)]
     fn from(value: Raw) -> Self {
@@ -423,6 +739,53 @@ impl<Raw: fmt::Debug, Invariant: crate::Test<Raw, 1>> Sigma<Raw, Invariant> {
         provisional
     }
 
+    /// Check all elements of a slice.
+    #[inline]
+    pub fn all_slice(slice: &[Raw]) -> &[Self] {
+        let pointer: *const [Raw] = slice;
+        #[expect(clippy::as_conversions, reason = "marked `repr(transparent)` above")]
+        let cast = pointer as *const [Self];
+        // SAFETY:
+        // `repr(transparent)`
+        let provisional = unsafe { &*cast };
+        for element in provisional {
+            element.check();
+        }
+        provisional
+    }
+
+    /// Check all elements of a mutable slice.
+    #[inline]
+    pub fn all_slice_mut(slice: &mut [Raw]) -> &mut [Self] {
+        let pointer: *mut [Raw] = slice;
+        #[expect(clippy::as_conversions, reason = "marked `repr(transparent)` above")]
+        let cast = pointer as *mut [Self];
+        // SAFETY:
+        // `repr(transparent)`
+        let provisional = unsafe { &mut *cast };
+        for element in &*provisional {
+            element.check();
+        }
+        provisional
+    }
+
+    /// Check all elements of a vector, reusing its allocation.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn all_vec(vec: Vec<Raw>) -> Vec<Self> {
+        let mut raw = ManuallyDrop::new(vec);
+        let (pointer, length, capacity) = (raw.as_mut_ptr(), raw.len(), raw.capacity());
+        // SAFETY:
+        // `repr(transparent)`, so size and alignment are identical,
+        // and the original vector will never be dropped.
+        let provisional = unsafe { Vec::from_raw_parts(pointer.cast::<Self>(), length, capacity) };
+        for element in &provisional {
+            element.check();
+        }
+        provisional
+    }
+
     /// Without changing its internal value,
     /// view one sigma-typed value as implementing another sigma type
     /// by checking the latter invariant at runtime (iff debug assertions are enabled).
@@ -606,6 +969,17 @@ impl<Raw: fmt::Debug, Invariant: crate::Test<Raw, 1>> Sigma<Raw, Invariant> {
         }
     }
 
+    /// Unwrap each element of a slice without copying.
+    #[inline(always)]
+    pub const fn strip_slice(slice: &[Self]) -> &[Raw] {
+        let pointer: *const [Self] = slice;
+        #[expect(clippy::as_conversions, reason = "marked `repr(transparent)` above")]
+        let cast = pointer as *const [Raw];
+        // SAFETY:
+        // `repr(transparent)`
+        unsafe { &*cast }
+    }
+
     /// Without changing its internal value,
     /// try to view one sigma-typed value as implementing another sigma type
     /// by checking the latter invariant at runtime.
@@ -647,6 +1021,65 @@ impl<Raw: fmt::Debug, Invariant: crate::Test<Raw, 1>> Sigma<Raw, Invariant> {
         Invariant::test([&self.raw])
     }
 
+    /// Apply a function to a term that implements a given invariant (say, A),
+    /// then check the output for a (possibly different) invariant (say, B)
+    /// without panicking.
+    /// # Errors
+    /// If the output does not satisfy B.
+    /// In this case, return the output, which displays why.
+    #[inline]
+    pub fn try_map<
+        OtherRaw: fmt::Debug,
+        OtherInvariant: crate::Test<OtherRaw, 1>,
+        F: FnOnce(Raw) -> OtherRaw,
+    >(
+        self,
+        f: F,
+    ) -> Result<Sigma<OtherRaw, OtherInvariant>, Rejected<OtherRaw, OtherInvariant>> {
+        Sigma::try_new(f(self.get())).map_err(Rejected::new)
+    }
+
+    /// Apply a function that mutates this value,
+    /// then check that the operation maintained this invariant without panicking.
+    /// # Errors
+    /// If the operation did not maintain this invariant.
+    /// In this case, roll back to a copy of the original value
+    /// and return the mutated value, which displays why.
+    #[inline]
+    pub fn try_map_mut<Y, F: FnOnce(&mut Raw) -> Y>(
+        &mut self,
+        f: F,
+    ) -> Result<Y, Rejected<Raw, Invariant>>
+    where
+        Raw: Clone,
+    {
+        let backup = self.raw.clone();
+        let y = f(&mut self.raw);
+        if self.try_check().is_ok() {
+            Ok(y)
+        } else {
+            Err(Rejected::new(mem::replace(&mut self.raw, backup)))
+        }
+    }
+
+    /// Apply a function to a term that implements a given invariant (say, A),
+    /// then check the output for a (possibly different) invariant (say, B)
+    /// without panicking.
+    /// # Errors
+    /// If the output does not satisfy B.
+    /// In this case, return the output, which displays why.
+    #[inline]
+    pub fn try_map_ref<
+        OtherRaw: fmt::Debug,
+        OtherInvariant: crate::Test<OtherRaw, 1>,
+        F: FnOnce(&Raw) -> OtherRaw,
+    >(
+        &self,
+        f: F,
+    ) -> Result<Sigma<OtherRaw, OtherInvariant>, Rejected<OtherRaw, OtherInvariant>> {
+        Sigma::try_new(f(self)).map_err(Rejected::new)
+    }
+
     /// Create a new sigma type instance by checking an invariant.
     /// # Errors
     /// If the invariant does not hold.
@@ -749,6 +1182,13 @@ impl<Raw: CanBeInfinite + fmt::Debug, Invariant: crate::Test<Raw>> CanBeInfinite
     }
 }
 
+impl<Raw: CanBeNaN + fmt::Debug, Invariant: crate::Test<Raw>> CanBeNaN for Sigma<Raw, Invariant> {
+    #[inline(always)]
+    fn check_not_nan(&self) -> bool {
+        self.raw.check_not_nan()
+    }
+}
+
 impl<Raw: Clone + fmt::Debug, Invariant: crate::Test<Raw, 1>> Clone for Sigma<Raw, Invariant> {
     #[inline(always)]
     fn clone(&self) -> Self {
@@ -771,12 +1211,7 @@ impl<Raw: Default + fmt::Debug, Invariant: crate::Test<Raw, 1>> Default for Sigm
     }
 }
 
-impl<Raw: Eq + fmt::Debug, Invariant: crate::Test<Raw, 1>> Eq for Sigma<Raw, Invariant> {
-    #[inline(always)]
-    fn assert_receiver_is_total_eq(&self) {
-        self.raw.assert_receiver_is_total_eq();
-    }
-}
+impl<Raw: Eq + fmt::Debug, Invariant: crate::Test<Raw, 1>> Eq for Sigma<Raw, Invariant> {}
 
 impl<I, Raw: FromIterator<I> + fmt::Debug, Invariant: crate::Test<Raw, 1>> FromIterator<I>
     for Sigma<Raw, Invariant>
@@ -0,0 +1,238 @@
+//! Type that maintains a given invariant relative to a runtime context.
+
+use {
+    crate::TestWith,
+    core::{
+        borrow::Borrow,
+        cmp::Ordering,
+        fmt,
+        hash::{Hash, Hasher},
+        marker::PhantomData,
+        ops,
+    },
+};
+
+/// Type that maintains a given invariant relative to a runtime context
+/// (e.g. an index less than the length of a particular slice).
+///
+/// Like `Sigma`, this checks only if debug assertions are enabled,
+/// and it's exactly its internal value in memory.
+/// Since the context is not stored, it's up to the caller
+/// to use this only alongside the context it was checked against.
+#[repr(transparent)]
+pub struct SigmaWith<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> {
+    /// Only to silence compiler errors.
+    phantom: PhantomData<Invariant>,
+    /// Only to silence compiler errors.
+    phantom_ctx: PhantomData<fn(&Ctx)>,
+    /// Internal type (to which this type will reduce in release builds).
+    raw: Raw,
+}
+
+impl<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> SigmaWith<Raw, Invariant, Ctx> {
+    /// Check an invariant in a given context if and only if debug assertions are enabled.
+    /// # Panics
+    /// If the invariant does not hold ***and*** debug assertions are enabled.
+    #[inline]
+    #[cfg(debug_assertions)]
+    pub fn check_with(&self, ctx: &Ctx) {
+        #[expect(
+            clippy::panic,
+            reason = "Returning a result would break API in release builds"
+        )]
+        match Invariant::test_with(&self.raw, ctx) {
+            Ok(()) => {}
+            Err(message) => {
+                panic!("{:#?} is not {}: {message}", self.raw, Invariant::ADJECTIVE);
+            }
+        }
+    }
+
+    /// Do nothing (since debug assertions are disabled).
+    #[inline]
+    #[cfg(not(debug_assertions))]
+    pub const fn check_with(&self, _ctx: &Ctx) {}
+
+    /// Unwrap the internal value that satisfies the invariant.
+    #[inline(always)]
+    pub fn get(self) -> Raw {
+        self.raw
+    }
+
+    /// Unwrap the internal value that satisfies the invariant.
+    #[inline(always)]
+    pub const fn get_ref(&self) -> &Raw {
+        &self.raw
+    }
+
+    /// Apply a function that mutates this value,
+    /// then check that the operation maintained this invariant in a given context.
+    #[inline]
+    pub fn map_mut_with<Y, F: FnOnce(&mut Raw) -> Y>(&mut self, ctx: &Ctx, f: F) -> Y {
+        let y = f(&mut self.raw);
+        self.check_with(ctx);
+        y
+    }
+
+    /// Create a new instance by checking an invariant in a given context.
+    /// # Panics
+    /// If the invariant does not hold ***and*** debug assertions are enabled.
+    #[inline]
+    #[cfg(debug_assertions)]
+    pub fn new_with(raw: Raw, ctx: &Ctx) -> Self {
+        let provisional = Self {
+            phantom: PhantomData,
+            phantom_ctx: PhantomData,
+            raw,
+        };
+        provisional.check_with(ctx);
+        provisional
+    }
+
+    /// Create a new instance by checking an invariant in a given context.
+    /// # Panics
+    /// If the invariant does not hold ***and*** debug assertions are enabled.
+    #[inline]
+    #[cfg(not(debug_assertions))]
+    pub const fn new_with(raw: Raw, _ctx: &Ctx) -> Self {
+        Self {
+            phantom: PhantomData,
+            phantom_ctx: PhantomData,
+            raw,
+        }
+    }
+
+    /// Check an invariant in a given context without panicking.
+    /// # Errors
+    /// If the invariant does not hold.
+    #[inline(always)]
+    pub fn try_check_with<'i>(&'i self, ctx: &'i Ctx) -> Result<(), Invariant::Error<'i>> {
+        Invariant::test_with(&self.raw, ctx)
+    }
+
+    /// Create a new instance by checking an invariant in a given context.
+    /// # Errors
+    /// If the invariant does not hold.
+    /// In this case, return the original input unchanged.
+    #[inline]
+    pub fn try_new_with(raw: Raw, ctx: &Ctx) -> Result<Self, Raw> {
+        let provisional = Self {
+            phantom: PhantomData,
+            phantom_ctx: PhantomData,
+            raw,
+        };
+
+        if provisional.try_check_with(ctx).is_ok() {
+            Ok(provisional)
+        } else {
+            Err(provisional.raw)
+        }
+    }
+}
+
+impl<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> AsRef<Raw>
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn as_ref(&self) -> &Raw {
+        &self.raw
+    }
+}
+
+impl<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Borrow<Raw>
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn borrow(&self) -> &Raw {
+        &self.raw
+    }
+}
+
+impl<Raw: Clone + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Clone
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        // No context to check against, but cloning shouldn't change anything:
+        Self {
+            phantom: PhantomData,
+            phantom_ctx: PhantomData,
+            raw: self.raw.clone(),
+        }
+    }
+}
+
+impl<Raw: Copy + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Copy
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+}
+
+impl<Raw: Eq + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Eq
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+}
+
+impl<Raw: Hash + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Hash
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<Raw: Ord + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> Ord
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl<Raw: PartialEq + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> PartialEq
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw.eq(&other.raw)
+    }
+}
+
+impl<Raw: PartialOrd + fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> PartialOrd
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.raw.partial_cmp(&other.raw)
+    }
+}
+
+impl<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> fmt::Debug
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.raw, f)
+    }
+}
+
+impl<Raw: fmt::Debug + fmt::Display, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> fmt::Display
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.raw, f)
+    }
+}
+
+impl<Raw: fmt::Debug, Invariant: TestWith<Ctx, Raw>, Ctx: ?Sized> ops::Deref
+    for SigmaWith<Raw, Invariant, Ctx>
+{
+    type Target = Raw;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.raw
+    }
+}
@@ -1,10 +1,47 @@
 //! Iterable data structure guaranteed to be sorted (optionally with or without duplicates).
 
 use {
-    crate::AllPairs,
-    core::{cmp::Ordering, fmt},
+    crate::{AllPairs, Const},
+    core::{cmp::Ordering, fmt, marker::PhantomData, ops},
 };
 
+/// Iterable data structure in which each element is
+/// at most a given tolerance less than the element before it.
+///
+/// Since only adjacent elements are compared,
+/// a long run of slightly decreasing elements can decrease by more than the tolerance overall.
+pub type ApproxSorted<Input, Tolerance> =
+    crate::Sigma<Input, ApproxSortedInvariant<Input, Tolerance>>;
+
+/// Iterable data structure in which each element is
+/// at most a given tolerance less than the element before it.
+pub type ApproxSortedInvariant<Input, Tolerance> = AllPairs<ApproxSortedPair<Tolerance>, Input>;
+
+/// Pair sorted left-to-right up to a given tolerance,
+/// i.e. in which the right element is at most that tolerance less than the left.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ApproxSortedPair<Tolerance>(PhantomData<Tolerance>);
+
+impl<Input: Clone + PartialOrd + ops::Add<Output = Input>, Tolerance: Const<Input>>
+    crate::Test<Input, 2> for ApproxSortedPair<Tolerance>
+{
+    const ADJECTIVE: &str = "approximately sorted";
+
+    type Error<'i>
+        = OutOfOrder
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([fst, snd]: [&Input; 2]) -> Result<(), Self::Error<'_>> {
+        match fst.partial_cmp(&snd.clone().add(Tolerance::VALUE)) {
+            None => Err(OutOfOrder::NoDefinedComparison),
+            Some(Ordering::Less | Ordering::Equal) => Ok(()),
+            Some(Ordering::Greater) => Err(OutOfOrder::Reversed),
+        }
+    }
+}
+
 /// Some elements in a supposedly sorted iterator were not sorted.
 #[expect(
     clippy::exhaustive_enums,
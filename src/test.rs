@@ -19,6 +19,8 @@ use {
 #[cfg(not(feature = "serde"))]
 use serde_json as _;
 
+use {core::hash::BuildHasher as _, std::hash::RandomState};
+
 #[cfg(feature = "quickcheck")]
 use {
     crate::Positive,
@@ -27,11 +29,14 @@ use {
 
 #[cfg(not(feature = "std"))]
 #[allow(unused_imports, reason = "complicated namespace resolution")]
-use alloc::{format, vec::Vec};
+use alloc::{format, string::String, vec::Vec};
 
 #[cfg(debug_assertions)]
 use std::panic::catch_unwind;
 
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+
 const _CHECK_ZERO_IMPL_FOR_NON_NEGATIVE: NonNegative<u8> = NonNegative::ZERO;
 
 #[quickcheck]
@@ -594,3 +599,1185 @@ fn serde_fallible_negative_i64(i: i64) -> TestResult {
         TestResult::error(format!("{i:#?} -> {json:#?} -> {rust:#?} =/= {i:#?}"))
     }
 }
+
+#[quickcheck]
+fn try_unique_vec(v: Vec<u8>) -> TestResult {
+    type Unique = crate::Unique<Vec<u8>>;
+    let actually_unique = {
+        let mut sorted = v.clone();
+        sorted.sort_unstable();
+        sorted.windows(2).all(|pair| pair.first() != pair.get(1))
+    };
+    match Unique::try_new(v) {
+        Ok(..) => {
+            if actually_unique {
+                TestResult::passed()
+            } else {
+                TestResult::error("not unique but passed")
+            }
+        }
+        Err(e) => {
+            if actually_unique {
+                TestResult::error(format!("unique but failed: {e:#?}"))
+            } else {
+                TestResult::passed()
+            }
+        }
+    }
+}
+
+#[quickcheck]
+#[cfg(feature = "alloc")]
+#[expect(clippy::needless_pass_by_value, reason = "required by `quickcheck`")]
+fn unique_strategies_agree(v: Vec<u8>) -> TestResult {
+    use crate::{ByEq, ByOrd, FindDuplicate as _};
+
+    let by_eq = ByEq::find_duplicate(&v);
+    let by_ord = ByOrd::find_duplicate(&v);
+    if by_eq != by_ord {
+        return TestResult::error(format!(
+            "`ByEq` found {by_eq:#?} but `ByOrd` found {by_ord:#?}"
+        ));
+    }
+    #[cfg(feature = "std")]
+    {
+        let by_hash = crate::ByHash::find_duplicate(&v);
+        if by_eq != by_hash {
+            return TestResult::error(format!(
+                "`ByEq` found {by_eq:#?} but `ByHash` found {by_hash:#?}"
+            ));
+        }
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+fn try_any_none_of_count_positive(v: Vec<i64>) -> TestResult {
+    use crate::usize::EqualToInvariant;
+
+    type Any = crate::Sigma<Vec<i64>, crate::Any<crate::PositiveInvariant<i64>, Vec<i64>>>;
+    type NoneOf = crate::Sigma<Vec<i64>, crate::NoneOf<crate::PositiveInvariant<i64>, Vec<i64>>>;
+    type ExactlyOne = crate::Sigma<
+        Vec<i64>,
+        crate::Count<crate::PositiveInvariant<i64>, Vec<i64>, EqualToInvariant<1>>,
+    >;
+    let positive = v.iter().filter(|&&i| i > 0).count();
+    if Any::try_new(v.clone()).is_ok() != (positive > 0) {
+        return TestResult::error(format!("`Any` disagreed on {positive} positive element(s)"));
+    }
+    if NoneOf::try_new(v.clone()).is_ok() != (positive == 0) {
+        return TestResult::error(format!(
+            "`NoneOf` disagreed on {positive} positive element(s)"
+        ));
+    }
+    if ExactlyOne::try_new(v).is_ok() != (positive == 1) {
+        return TestResult::error(format!(
+            "`Count` disagreed on {positive} positive element(s)"
+        ));
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[cfg(feature = "alloc")]
+fn try_all_values_on_unit(map: BTreeMap<u8, f64>) -> TestResult {
+    type Weights = crate::Sigma<
+        BTreeMap<u8, f64>,
+        crate::AllValues<crate::OnUnitInvariant<f64, true, true>, BTreeMap<u8, f64>>,
+    >;
+    let actually_on_unit = map.values().all(|f| (0_f64..=1_f64).contains(f));
+    match Weights::try_new(map) {
+        Ok(..) => {
+            if actually_on_unit {
+                TestResult::passed()
+            } else {
+                TestResult::error("not all on unit but passed")
+            }
+        }
+        Err(e) => {
+            if actually_on_unit {
+                TestResult::error(format!("all on unit but failed: {e:#?}"))
+            } else {
+                TestResult::passed()
+            }
+        }
+    }
+}
+
+#[quickcheck]
+#[expect(clippy::needless_pass_by_value, reason = "required by `quickcheck`")]
+fn try_all_keys_and_entries(pairs: Vec<(i64, i64)>) -> TestResult {
+    /// Entries whose key is less than their value.
+    struct KeyLessThanValue;
+
+    impl<'e> crate::Test<(&'e i64, &'e i64), 1> for KeyLessThanValue {
+        const ADJECTIVE: &'static str = "increasing";
+        type Error<'i>
+            = &'static str
+        where
+            (&'e i64, &'e i64): 'i;
+
+        fn test<'i>([&(k, v)]: [&'i (&'e i64, &'e i64); 1]) -> Result<(), Self::Error<'i>> {
+            if k < v { Ok(()) } else { Err("key >= value") }
+        }
+    }
+
+    type Keys<'p> = crate::Sigma<
+        &'p [(i64, i64)],
+        crate::AllKeys<crate::PositiveInvariant<i64>, &'p [(i64, i64)]>,
+    >;
+    type Entries<'p> =
+        crate::Sigma<&'p [(i64, i64)], crate::AllEntries<KeyLessThanValue, &'p [(i64, i64)]>>;
+
+    let keys_positive = pairs.iter().all(|&(k, _)| k > 0);
+    let entries_increasing = pairs.iter().all(|&(k, v)| k < v);
+    if Keys::try_new(&pairs).is_ok() != keys_positive {
+        return TestResult::error("`AllKeys` disagreed");
+    }
+    if Entries::try_new(&pairs).is_ok() != entries_increasing {
+        return TestResult::error("`AllEntries` disagreed");
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[cfg(debug_assertions)]
+#[expect(clippy::needless_pass_by_value, reason = "required by `quickcheck`")]
+fn all_slice_positive(v: Vec<i64>) -> TestResult {
+    type Positive = crate::Positive<i64>;
+    let ground_truth = v.iter().all(|&i| i > 0);
+    match catch_unwind(|| Positive::strip_slice(Positive::all_slice(&v)) == v.as_slice()) {
+        Ok(roundtrip) => {
+            if !roundtrip {
+                TestResult::error("`strip_slice(all_slice(..))` changed its input")
+            } else if ground_truth {
+                TestResult::passed()
+            } else {
+                TestResult::error("not all positive but passed")
+            }
+        }
+        Err(e) => {
+            if ground_truth {
+                TestResult::error(format!("all positive but failed: {e:#?}"))
+            } else {
+                TestResult::passed()
+            }
+        }
+    }
+}
+
+#[quickcheck]
+#[cfg(all(debug_assertions, feature = "alloc"))]
+fn all_vec_positive(v: Vec<i64>) -> TestResult {
+    type Positive = crate::Positive<i64>;
+    let ground_truth = v.iter().all(|&i| i > 0);
+    let expected = v.clone();
+    match catch_unwind(|| Positive::all_vec(v)) {
+        Ok(ok) => {
+            if Positive::strip_slice(&ok) != expected.as_slice() {
+                TestResult::error("`all_vec(..)` changed its input")
+            } else if ground_truth {
+                TestResult::passed()
+            } else {
+                TestResult::error("not all positive but passed")
+            }
+        }
+        Err(e) => {
+            if ground_truth {
+                TestResult::error(format!("all positive but failed: {e:#?}"))
+            } else {
+                TestResult::passed()
+            }
+        }
+    }
+}
+
+#[quickcheck]
+#[expect(clippy::needless_pass_by_value, reason = "required by `quickcheck`")]
+fn iter_filter_valid_and_try_checked_positive(v: Vec<i64>) -> TestResult {
+    use crate::{PositiveInvariant, SigmaIterExt as _};
+
+    let expected: Vec<i64> = v.iter().copied().filter(|&i| i > 0).collect();
+    let filtered: Vec<i64> = v
+        .iter()
+        .copied()
+        .filter_valid::<PositiveInvariant<i64>>()
+        .map(crate::Sigma::get)
+        .collect();
+    if filtered != expected {
+        return TestResult::error(format!("`filter_valid` yielded {filtered:#?}"));
+    }
+    let tried: Vec<i64> = v
+        .iter()
+        .copied()
+        .try_checked::<PositiveInvariant<i64>>()
+        .filter_map(Result::ok)
+        .map(crate::Sigma::get)
+        .collect();
+    if tried != expected {
+        return TestResult::error(format!("`try_checked` yielded {tried:#?}"));
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[cfg(debug_assertions)]
+fn iter_checked_positive(v: Vec<i64>) -> TestResult {
+    use crate::{PositiveInvariant, SigmaIterExt as _};
+
+    let ground_truth = v.iter().all(|&i| i > 0);
+    match catch_unwind(|| v.into_iter().checked::<PositiveInvariant<i64>>().count()) {
+        Ok(..) => {
+            if ground_truth {
+                TestResult::passed()
+            } else {
+                TestResult::error("not all positive but passed")
+            }
+        }
+        Err(e) => {
+            if ground_truth {
+                TestResult::error(format!("all positive but failed: {e:#?}"))
+            } else {
+                TestResult::passed()
+            }
+        }
+    }
+}
+
+#[quickcheck]
+fn positive_checked_and_saturating_add_i32(lhs: i32, rhs: i32) -> TestResult {
+    use crate::{CheckedAdd as _, SaturatingAdd as _};
+
+    type Positive = crate::Positive<i32>;
+    let (Ok(positive_lhs), Ok(positive_rhs)) = (Positive::try_new(lhs), Positive::try_new(rhs))
+    else {
+        return TestResult::discard();
+    };
+    let checked = positive_lhs
+        .checked_add(positive_rhs)
+        .map(crate::Sigma::get);
+    if checked != lhs.checked_add(rhs) {
+        return TestResult::error(format!("{lhs} + {rhs} checked to {checked:#?}"));
+    }
+    let saturated = positive_lhs.saturating_add(positive_rhs).get();
+    if saturated != lhs.saturating_add(rhs) {
+        return TestResult::error(format!("{lhs} + {rhs} saturated to {saturated}"));
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[expect(clippy::arithmetic_side_effects, reason = "testing exactly that")]
+fn on_unit_mul_and_complement_dont_panic(lhs: f64, rhs: f64) -> TestResult {
+    let (Ok(open), Ok(closed)) = (
+        crate::OnUnit::<f64, false, false>::try_new(lhs.fract().abs()),
+        crate::OnUnit::<f64, true, true>::try_new(rhs.fract().abs()),
+    ) else {
+        return TestResult::discard();
+    };
+    let _: crate::OnUnit<f64, true, false> = open * closed;
+    let _: crate::OnUnit<f64, true, true> = closed * closed;
+    let _: crate::OnUnit<f64, false, true> = open.complement();
+    let _: crate::OnUnit<f64, true, true> = closed.complement();
+    TestResult::passed()
+}
+
+#[quickcheck]
+fn non_zero_checked_mul_is_never_zero(lhs: crate::NonZero<u8>, rhs: crate::NonZero<u8>) -> bool {
+    use crate::CheckedMul as _;
+
+    lhs.checked_mul(rhs).map(crate::Sigma::get) == lhs.get().checked_mul(rhs.get())
+}
+
+#[test]
+fn non_zero_checked_mul_overflow() {
+    use crate::CheckedMul as _;
+
+    let sixteen = crate::NonZero::<u8>::new(16);
+    assert_eq!(sixteen.checked_mul(sixteen), None);
+    assert_eq!(
+        sixteen
+            .checked_mul(crate::NonZero::<u8>::new(15))
+            .map(crate::Sigma::get),
+        Some(240)
+    );
+}
+
+#[cfg(feature = "std")]
+#[quickcheck]
+#[expect(clippy::float_cmp, reason = "each side is computed identically")]
+fn float_math_matches_raw(x: f64, n: i32) -> TestResult {
+    let Ok(finite) = crate::Finite::<f64>::try_new(x) else {
+        return TestResult::discard();
+    };
+    if finite.proven_abs().get() != x.abs()
+        || finite.proven_signum().get() != x.signum()
+        || finite.proven_exp().get() != x.exp()
+        || finite.powi_even::<2>().get() != x.powi(2)
+        || finite.proven_abs().proven_sqrt().get() != x.abs().sqrt()
+        || finite.proven_abs().proven_powi(n).get() != x.abs().powi(n)
+    {
+        return TestResult::error(format!("{x} (finite)"));
+    }
+    if finite.proven_ln().map(crate::Sigma::get) != (x > 0_f64).then(|| x.ln()) {
+        return TestResult::error(format!("{x} (logarithm)"));
+    }
+    if let Ok(positive) = crate::Positive::<f64>::try_new(x)
+        && (positive.proven_recip().get() != x.recip()
+            || positive.proven_sqrt().get() != x.sqrt()
+            || positive.proven_signum().get() != 1_f64)
+    {
+        return TestResult::error(format!("{x} (positive)"));
+    }
+    if let Ok(negative) = crate::Negative::<f64>::try_new(x)
+        && (negative.proven_abs().get() != -x
+            || negative.proven_recip().get() != x.recip()
+            || negative.powi_odd::<3>().get() != x.powi(3)
+            || negative.powi_even::<-2>().get() != x.powi(-2)
+            || negative.proven_signum().get() != -1_f64)
+    {
+        return TestResult::error(format!("{x} (negative)"));
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+fn total_agrees_with_partial_ord(lhs: f64, rhs: f64) -> TestResult {
+    let (Ok(not_nan_lhs), Ok(not_nan_rhs)) = (
+        crate::NotNaN::<f64>::try_new(lhs),
+        crate::NotNaN::<f64>::try_new(rhs),
+    ) else {
+        return TestResult::discard();
+    };
+    let (total_lhs, total_rhs) = (
+        crate::Total::new(not_nan_lhs),
+        crate::Total::new(not_nan_rhs),
+    );
+    if Some(total_lhs.cmp(&total_rhs)) != lhs.partial_cmp(&rhs) {
+        return TestResult::error(format!(
+            "{lhs} vs. {rhs} ordered as {:#?}",
+            total_lhs.cmp(&total_rhs)
+        ));
+    }
+    let build = RandomState::new();
+    if total_lhs == total_rhs && build.hash_one(total_lhs) != build.hash_one(total_rhs) {
+        return TestResult::error(format!("{lhs} == {rhs} but hashed differently"));
+    }
+    TestResult::passed()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn float_math_at_zero_and_infinity() {
+    let negative_zero = crate::NonNegative::<f64>::new(-0_f64);
+    assert!(negative_zero.proven_powi(-1).get().is_infinite());
+    assert!(negative_zero.proven_powi(-3).get().is_infinite());
+    assert!(negative_zero.proven_powi(3).get().is_sign_positive());
+
+    let infinity = crate::Positive::<f64>::new(f64::INFINITY);
+    assert!(infinity.proven_recip().get().is_sign_positive());
+    let negative_infinity = crate::Negative::<f64>::new(f64::NEG_INFINITY);
+    assert!(negative_infinity.proven_recip().get().is_sign_negative());
+}
+
+#[test]
+fn total_negative_zero_hashes_like_zero() {
+    let build = RandomState::new();
+    let zero = crate::Total::new(crate::NotNaN::<f64>::new(0_f64));
+    let negative_zero = crate::Total::new(crate::NotNaN::<f64>::new(-0_f64));
+    assert_eq!(zero, negative_zero);
+    assert_eq!(build.hash_one(zero), build.hash_one(negative_zero));
+}
+
+#[quickcheck]
+#[expect(clippy::arithmetic_side_effects, reason = "testing exactly that")]
+fn parity_arithmetic_doesnt_panic(lhs: i32, rhs: i32) -> TestResult {
+    if lhs.checked_add(rhs).is_none()
+        || lhs.checked_sub(rhs).is_none()
+        || lhs.checked_mul(rhs).is_none()
+    {
+        return TestResult::discard();
+    }
+    match (crate::Even::try_new(lhs), crate::Even::try_new(rhs)) {
+        (Ok(even_lhs), Ok(even_rhs)) => {
+            let _: crate::Even<i32> = even_lhs + even_rhs;
+            let _: crate::Even<i32> = even_lhs - even_rhs;
+            let _: crate::Even<i32> = even_lhs * even_rhs;
+        }
+        (Ok(even_lhs), Err(_)) => {
+            let odd_rhs = crate::Odd::new(rhs);
+            let _: crate::Odd<i32> = even_lhs + odd_rhs;
+            let _: crate::Odd<i32> = even_lhs - odd_rhs;
+            let _: crate::Even<i32> = even_lhs * odd_rhs;
+        }
+        (Err(_), Ok(even_rhs)) => {
+            let odd_lhs = crate::Odd::new(lhs);
+            let _: crate::Odd<i32> = odd_lhs + even_rhs;
+            let _: crate::Odd<i32> = odd_lhs - even_rhs;
+            let _: crate::Even<i32> = odd_lhs * even_rhs;
+        }
+        (Err(_), Err(_)) => {
+            let (odd_lhs, odd_rhs) = (crate::Odd::new(lhs), crate::Odd::new(rhs));
+            let _: crate::Even<i32> = odd_lhs + odd_rhs;
+            let _: crate::Even<i32> = odd_lhs - odd_rhs;
+            let _: crate::Odd<i32> = odd_lhs * odd_rhs;
+            if odd_lhs.trailing_zeros().get() != 0 {
+                return TestResult::error(format!("{lhs} had trailing zeros"));
+            }
+        }
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[expect(clippy::arithmetic_side_effects, reason = "testing exactly that")]
+fn power_of_two_multiple_of_and_aligned(lhs_log: u8, rhs_log: u8, factor: u64) -> TestResult {
+    use crate::{CheckedMul as _, u64::MultipleOf};
+
+    let (Some(lhs), Some(rhs)) = (
+        1_u64.checked_shl(u32::from(lhs_log)),
+        1_u64.checked_shl(u32::from(rhs_log)),
+    ) else {
+        return TestResult::discard();
+    };
+    let (power_lhs, power_rhs) = (crate::PowerOfTwo::new(lhs), crate::PowerOfTwo::new(rhs));
+    if power_lhs.ilog2().get() != u32::from(lhs_log)
+        || power_lhs.trailing_zeros().get() != u32::from(lhs_log)
+    {
+        return TestResult::error(format!("{lhs} had the wrong logarithm"));
+    }
+    let checked = power_lhs.checked_mul(power_rhs).map(crate::Sigma::get);
+    if checked != lhs.checked_mul(rhs) {
+        return TestResult::error(format!("{lhs} * {rhs} checked to {checked:#?}"));
+    }
+    let Some(multiple) = factor.checked_mul(12) else {
+        return TestResult::discard();
+    };
+    let multiple_of = MultipleOf::<12>::new(multiple);
+    let aligned = crate::Aligned::<u64, 4>::new(multiple);
+    if multiple.checked_add(multiple).is_some() {
+        let _: MultipleOf<12> = multiple_of + multiple_of;
+        let _: crate::Aligned<u64, 4> = aligned + aligned;
+    }
+    let _: MultipleOf<12> = multiple_of - multiple_of;
+    if crate::Aligned::<u64, 8>::try_new(multiple).is_ok() != (multiple.trailing_zeros() >= 3) {
+        return TestResult::error(format!("{multiple} was misaligned"));
+    }
+    TestResult::passed()
+}
+
+#[cfg(not(debug_assertions))]
+#[test]
+fn core_non_zero_rejects_unchecked_zero() {
+    use core::num;
+
+    let non_zero = num::NonZero::try_from(crate::NonZero::<u32>::new(0)).map_err(|e| e.get());
+    assert_eq!(non_zero, Err(0));
+    let positive = num::NonZero::try_from(crate::Positive::<u8>::new(0)).map_err(|e| e.get());
+    assert_eq!(positive, Err(0));
+}
+
+#[quickcheck]
+fn core_non_zero_round_trip(x: u32) -> TestResult {
+    use core::num;
+
+    let Some(core_non_zero) = num::NonZero::new(x) else {
+        return TestResult::discard();
+    };
+    let non_zero = crate::NonZero::from(core_non_zero);
+    let positive = crate::Positive::new(x);
+    if num::NonZero::try_from(non_zero) != Ok(core_non_zero)
+        || num::NonZero::try_from(positive) != Ok(core_non_zero)
+    {
+        return TestResult::error(format!("{x} didn't round-trip"));
+    }
+    TestResult::passed()
+}
+
+#[quickcheck]
+#[expect(clippy::needless_pass_by_value, reason = "required by `quickcheck`")]
+fn text_invariants_agree_with_str(string: String) -> bool {
+    use crate::{Ascii, Lowercase, MaxLen, NoControlChars, NonEmptyStr, Trimmed};
+
+    let s: &str = &string;
+    Ascii::try_new(s).is_ok() == s.is_ascii()
+        && Lowercase::try_new(s).is_err() == s.chars().any(char::is_uppercase)
+        && NoControlChars::try_new(s).is_err() == s.chars().any(char::is_control)
+        && NonEmptyStr::try_new(s).is_err() == s.is_empty()
+        && Trimmed::try_new(s).is_ok() == (s.trim() == s)
+        && MaxLen::<_, 8>::try_new(s).is_ok() == (s.len() <= 8)
+}
+
+#[test]
+fn text_identifiers_and_offsets() {
+    use crate::{AsciiInvariant, Identifier, Test as _};
+
+    let valid = ["x", "_x", "snake_case_2", "CamelCase"];
+    assert!(valid.iter().all(|s| Identifier::try_new(*s).is_ok()));
+    let invalid = ["", "_", "2x", "kebab-case", "caf\u{e9}"];
+    assert!(invalid.iter().all(|s| Identifier::try_new(*s).is_err()));
+    assert!(Identifier::try_new('x').is_ok_and(|x| x.get() == 'x'));
+    let error = AsciiInvariant::<&str>::test([&"na\u{ef}ve"]).map_err(|e| format!("{e}"));
+    assert_eq!(error, Err("'\u{ef}' at byte offset 2 is not ASCII".into()));
+}
+
+#[test]
+fn text_patterns() {
+    use crate::{Matches, MatchesInvariant, Pattern, Test as _, valid_pattern};
+
+    #[derive(Debug)]
+    struct Slug;
+    impl Pattern for Slug {
+        const PATTERN: &'static str = "[a-z0-9][a-z0-9-]*";
+    }
+
+    #[derive(Debug)]
+    struct Version;
+    impl Pattern for Version {
+        const PATTERN: &'static str = "[0-9]+\\.[0-9]+\\.[0-9]+";
+    }
+
+    #[derive(Debug)]
+    struct NearMiss;
+    impl Pattern for NearMiss {
+        const PATTERN: &'static str = "a*a*a*a*a*a*a*a*b";
+    }
+
+    #[derive(Debug)]
+    struct NotDigits;
+    impl Pattern for NotDigits {
+        const PATTERN: &'static str = "[!0-9]*";
+    }
+
+    let valid = ["", "?*", "[a-]", "\\[x\\]", "[\\]]+"];
+    assert!(valid.iter().all(|p| valid_pattern(p)));
+    let invalid = ["*", "a+*", "[]", "[!]", "[a-z", "a\\"];
+    assert!(!invalid.iter().any(|p| valid_pattern(p)));
+
+    let slugs = ["a", "sigma-types", "x-1-y"];
+    assert!(
+        slugs
+            .iter()
+            .all(|s| Matches::<_, Slug>::try_new(*s).is_ok())
+    );
+    let not_slugs = ["", "-a", "Sigma", "sigma_types"];
+    assert!(
+        not_slugs
+            .iter()
+            .all(|s| Matches::<_, Slug>::try_new(*s).is_err())
+    );
+    let versions = ["0.1.0", "12.34.56"];
+    assert!(
+        versions
+            .iter()
+            .all(|s| Matches::<_, Version>::try_new(*s).is_ok())
+    );
+    let not_versions = ["", "1.2", "1.2.", "1x2.3", "1.2.3.4"];
+    assert!(
+        not_versions
+            .iter()
+            .all(|s| Matches::<_, Version>::try_new(*s).is_err())
+    );
+    let not_digits = ["", "abc", "x.y"];
+    assert!(
+        not_digits
+            .iter()
+            .all(|s| Matches::<_, NotDigits>::try_new(*s).is_ok())
+    );
+    assert!(
+        ["1", "abc1"]
+            .iter()
+            .all(|s| Matches::<_, NotDigits>::try_new(*s).is_err())
+    );
+    let many = "a".repeat(63);
+    assert!(valid_pattern(&many));
+    assert!(!valid_pattern(&format!("{many}[a-z]*")));
+    assert!(valid_pattern(&"\u{e9}".repeat(63)));
+
+    // Exponential if backtracking:
+    let a = "a".repeat(10_000);
+    let near_miss =
+        MatchesInvariant::<&str, NearMiss>::test([&a.as_str()]).map_err(|e| format!("{e}"));
+    assert_eq!(
+        near_miss,
+        Err("Diverged from the pattern \"a*a*a*a*a*a*a*a*b\" at byte offset 10000".into())
+    );
+    let hit = format!("{a}b");
+    assert!(Matches::<_, NearMiss>::try_new(hit.as_str()).is_ok_and(|s| s.len() == 10_001));
+
+    let error = MatchesInvariant::<&str, Version>::test([&"1.23x.4"]).map_err(|e| format!("{e}"));
+    assert_eq!(
+        error,
+        Err("Diverged from the pattern \"[0-9]+\\\\.[0-9]+\\\\.[0-9]+\" at byte offset 4".into())
+    );
+}
+
+#[quickcheck]
+fn encoded_utf8_agrees_with_string(bytes: Vec<u8>) -> bool {
+    use crate::Utf8Bytes;
+
+    let expected = String::from_utf8(bytes.clone()).ok();
+    let utf8 = Utf8Bytes::try_new(bytes).ok();
+    utf8.as_ref().map(Utf8Bytes::as_str) == expected.as_deref()
+}
+
+#[quickcheck]
+fn encoded_hex_round_trip(bytes: Vec<u8>) -> bool {
+    use crate::{Hex, Lower, Mixed, Upper};
+
+    let lower: String = bytes
+        .iter()
+        .flat_map(|byte| [byte.wrapping_shr(4), byte & 0xF])
+        .filter_map(|digit| char::from_digit(digit.into(), 16))
+        .collect();
+    let upper = lower.to_uppercase();
+    Hex::<_, Mixed>::try_new(upper.as_str()).is_ok()
+        && Hex::<_, Lower>::try_new(upper.as_str()).is_ok()
+            == upper.bytes().all(|byte| byte.is_ascii_digit())
+        && Hex::<_, Upper>::try_new(upper.as_str())
+            .is_ok_and(|hex| hex.decoded().eq(bytes.iter().copied()))
+        && Hex::<_, Lower>::try_new(lower.as_str()).is_ok_and(|hex| hex.decoded().eq(bytes))
+}
+
+#[test]
+fn encoded_base64_vectors() {
+    use crate::{Base64, Base64Invariant, Standard, Test as _, UrlSafe};
+
+    // From RFC 4648, section 10:
+    let vectors = [
+        ("", ""),
+        ("Zg==", "f"),
+        ("Zm8=", "fo"),
+        ("Zm9v", "foo"),
+        ("Zm9vYg==", "foob"),
+        ("Zm9vYmE=", "fooba"),
+        ("Zm9vYmFy", "foobar"),
+    ];
+    assert!(vectors.iter().all(|&(encoded, decoded)| {
+        Base64::<_, Standard>::try_new(encoded)
+            .is_ok_and(|base64| base64.decoded().eq(decoded.bytes()))
+            && Base64::<_, UrlSafe>::try_new(encoded.trim_end_matches('='))
+                .is_ok_and(|base64| base64.decoded().eq(decoded.bytes()))
+    }));
+    assert!(
+        Base64::<_, Standard>::try_new("+/8=")
+            .is_ok_and(|base64| base64.decoded().eq([0xFB, 0xFF]))
+    );
+    assert!(
+        Base64::<_, UrlSafe>::try_new("-_8").is_ok_and(|base64| base64.decoded().eq([0xFB, 0xFF]))
+    );
+    let invalid = ["Z", "Zg=", "Zg===", "Z=g=", "-_8=", "Zm9v\n"];
+    assert!(
+        invalid
+            .iter()
+            .all(|s| Base64::<_, Standard>::try_new(*s).is_err())
+    );
+    let error = Base64Invariant::<&str, Standard>::test([&"Zm=v"]).map_err(|e| format!("{e}"));
+    assert_eq!(
+        error,
+        Err("'v' at byte offset 3 can't appear there in base64".into())
+    );
+}
+
+#[quickcheck]
+fn probability_conversions(numerator: u32) -> bool {
+    use crate::Probability;
+
+    let p = Probability::new(f64::from(numerator) / f64::from(u32::MAX));
+    let odds = p.odds().get();
+    let complement_odds = p.complement().odds().get();
+    odds.mul_add(complement_odds, -1_f64).abs() < 1e-6_f64
+        || odds == 0_f64
+        || complement_odds == 0_f64
+}
+
+#[cfg(feature = "std")]
+#[quickcheck]
+fn log_probability_round_trip(numerator: u32) -> bool {
+    use {crate::Probability, core::ops::Mul as _};
+
+    let p = Probability::new(f64::from(numerator) / f64::from(u32::MAX));
+    let log_odds = p.log_odds().get();
+    (p.proven_ln().proven_exp().get() - p.get()).abs() < 1e-12_f64
+        && (log_odds > 0_f64) == (p.get() > 0.5_f64)
+        && p.mul(p).proven_ln().get() <= p.proven_ln().get()
+}
+
+#[test]
+fn probability_distributions() {
+    use crate::{Distribution, DistributionInvariant, Epsilons, Test as _};
+
+    type Tenths = Distribution<f64, 10, Epsilons<16>>;
+    type Exact = Distribution<f64, 10, Epsilons<0>>;
+
+    let tenths = [0.1_f64; 10];
+    let mut skewed = tenths;
+    skewed[0] = 0.5_f64;
+    // Ten tenths sum to slightly less than one:
+    assert!(
+        Tenths::try_new(tenths).is_ok()
+            && Exact::try_new(tenths).is_err()
+            && Tenths::try_new(skewed).is_err()
+    );
+    let mut negative = tenths;
+    negative[0] = -0.1_f64;
+    negative[1] = 0.3_f64;
+    let error = DistributionInvariant::<f64, 10, Epsilons<16>>::test([&negative])
+        .map_err(|e| format!("{e}"));
+    assert_eq!(error, Err("Element #0 (-0.1) is not on [0, 1]".into()));
+}
+
+#[test]
+fn approx_invariants_tolerate_rounding() {
+    use crate::{
+        ApproxEq, ApproxOnUnit, ApproxSorted, Const, Epsilons, OnUnit, Test as _,
+        approx::ApproxEqInvariant,
+    };
+
+    #[derive(Debug)]
+    struct Three;
+    impl Const<f64> for Three {
+        const VALUE: f64 = 3_f64;
+    }
+
+    // Each of these rounds to slightly off:
+    let almost_one = 0.1_f64 * 3_f64 / 0.3_f64;
+    let almost_three = 0.3_f64 / 0.1_f64;
+    assert!(
+        ApproxOnUnit::<_, Epsilons<4>>::try_new(almost_one).is_ok()
+            && ApproxOnUnit::<_, Epsilons<4>>::try_new(1.01_f64).is_err()
+            && OnUnit::<_, true, true>::try_new(almost_one).is_err()
+            && ApproxEq::<_, Three, Epsilons<4>>::try_new(almost_three).is_ok()
+            && ApproxEq::<_, Three, Epsilons<4>>::try_new(3.1_f64).is_err()
+            && ApproxSorted::<_, Epsilons<4>>::try_new([0_f64, almost_one, 1_f64]).is_ok()
+            && ApproxSorted::<_, Epsilons<4>>::try_new([0_f64, 1_f64, 0.9_f64]).is_err()
+    );
+    let snapped_to_one = OnUnit::<_, true, true>::new_clamped::<Epsilons<4>>(almost_one);
+    assert!(snapped_to_one == OnUnit::<_, true, true>::new(1_f64));
+    let snapped_to_zero = OnUnit::<_, true, true>::new_clamped::<Epsilons<4>>(-f64::EPSILON);
+    assert!(snapped_to_zero == OnUnit::<_, true, true>::new(0_f64));
+    let error =
+        ApproxEqInvariant::<f64, Three, Epsilons<0>>::test([&3.5_f64]).map_err(|e| format!("{e}"));
+    assert_eq!(error, Err("3.5 is not within 0.0 of 3.0".into()));
+}
+
+#[test]
+fn intervals_between_constants() {
+    use crate::{
+        Const, ConstOne, ConstZero, Interval, IntervalInvariant, MinusOne, Pi, Tau, Test as _,
+    };
+
+    type Cosine = Interval<f64, MinusOne, ConstOne, true, true>;
+    type Angle = Interval<f64, ConstZero, Tau, true, false>;
+    type Byte = Interval<i16, ConstZero, U8Max, true, true>;
+
+    #[derive(Debug)]
+    struct U8Max;
+    impl Const<i16> for U8Max {
+        const VALUE: i16 = 255_i16;
+    }
+
+    let cosines = [-1_f64, -0.5_f64, 0_f64, 1_f64];
+    assert!(cosines.iter().all(|&x| Cosine::try_new(x).is_ok()));
+    let not_cosines = [-1.5_f64, 1.000_000_1_f64, f64::NAN];
+    assert!(not_cosines.iter().all(|&x| Cosine::try_new(x).is_err()));
+    assert!(
+        Angle::try_new(0_f64).is_ok()
+            && Angle::try_new(<Pi as Const<f64>>::VALUE).is_ok()
+            && Angle::try_new(<Tau as Const<f64>>::VALUE).is_err()
+            && Byte::try_new(255_i16).is_ok()
+            && Byte::try_new(256_i16).is_err()
+    );
+    let error = IntervalInvariant::<f64, ConstZero, Tau, true, false>::test([&-1_f64])
+        .map_err(|e| format!("{e}"));
+    assert_eq!(error, Err("Not on [0.0, 6.283185307179586): -1.0".into()));
+}
+
+#[quickcheck]
+fn angles_wrap_instead_of_panicking(lhs: f64, rhs: f64) -> TestResult {
+    use {
+        crate::{Degrees, Finite, Radians, SignedDegrees, SignedRadians},
+        core::{
+            f64::consts::TAU,
+            ops::{Add as _, Neg as _, Sub as _},
+        },
+    };
+
+    let (Ok(finite_lhs), Ok(finite_rhs)) = (Finite::try_new(lhs), Finite::try_new(rhs)) else {
+        return TestResult::discard();
+    };
+    // Each of these panics in debug builds if it lands outside its range:
+    let radians = Radians::<f64>::normalize(finite_lhs).sub(Radians::<f64>::normalize(finite_rhs));
+    let signed_radians = SignedRadians::<f64>::normalize(finite_lhs)
+        .add(SignedRadians::<f64>::normalize(finite_rhs).neg());
+    let degrees = Degrees::<f64>::normalize(finite_lhs).add(Degrees::<f64>::normalize(finite_rhs));
+    let signed_degrees = SignedDegrees::<f64>::normalize(finite_lhs)
+        .sub(SignedDegrees::<f64>::normalize(finite_rhs));
+    let close = |a: f64, b: f64, turn: f64| {
+        let diff = (a - b).abs();
+        diff.min(turn - diff) <= 1e-9_f64 * turn
+    };
+    TestResult::from_bool(
+        close(radians.to_signed().get(), signed_radians.get(), TAU)
+            && close(
+                radians.to_degrees().get(),
+                radians.get().to_degrees(),
+                360_f64,
+            )
+            && close(
+                degrees.to_signed().to_unsigned().get(),
+                degrees.get(),
+                360_f64,
+            )
+            && close(
+                signed_degrees.to_radians().get(),
+                signed_degrees.get().to_radians(),
+                TAU,
+            ),
+    )
+}
+
+#[test]
+fn angles_normalize_onto_their_ranges() {
+    use {
+        crate::{Degrees, Finite, Radians, SignedDegrees, SignedRadians},
+        core::f64::consts::PI,
+    };
+
+    #[expect(clippy::float_cmp, reason = "exactly representable")]
+    let exact = Degrees::<f64>::normalize(Finite::new(-90_f64)).get() == 270_f64
+        && SignedDegrees::<f64>::normalize(Finite::new(180_f64)).get() == 180_f64
+        && SignedDegrees::<f64>::normalize(Finite::new(-180_f64)).get() == 180_f64
+        && SignedDegrees::<f64>::normalize(Finite::new(540_f64)).get() == 180_f64
+        && Degrees::<f64>::normalize(Finite::new(720_f64)).get() == 0_f64
+        && Radians::<f64>::normalize(Finite::new(-1e-300_f64)).get() == 0_f64
+        && SignedRadians::<f64>::normalize(Finite::new(-PI)).get() == PI;
+    assert!(exact);
+}
+
+#[cfg(feature = "std")]
+#[quickcheck]
+fn angle_trig_matches_raw(x: f64) -> TestResult {
+    use crate::{Degrees, Finite, Radians};
+
+    // Large inputs lose too much precision when normalized to compare:
+    let Ok(finite) = Finite::try_new(x) else {
+        return TestResult::discard();
+    };
+    if x.abs() > 1e6_f64 {
+        return TestResult::discard();
+    }
+    let radians = Radians::<f64>::normalize(finite);
+    let degrees = radians.to_degrees();
+    #[expect(clippy::float_cmp, reason = "exactly representable")]
+    let right_angle = Degrees::<f64>::normalize(Finite::new(90_f64)).sin().get() == 1_f64;
+    TestResult::from_bool(
+        (radians.sin().get() - x.sin()).abs() < 1e-9_f64
+            && (degrees.cos().get() - x.cos()).abs() < 1e-9_f64
+            && right_angle,
+    )
+}
+
+#[test]
+fn matrix_structure() {
+    use crate::{
+        Diagonal, Epsilons, Orthonormal, RowStochastic, Symmetric, SymmetricInvariant, Test as _,
+        UpperTriangular, UpperTriangularInvariant,
+    };
+
+    let rotation = [[0.6_f64, -0.8_f64], [0.8_f64, 0.6_f64]];
+    let sheared = [[1_f64, 1_f64], [0_f64, 1_f64]];
+    let scaled = [[2_f64, 0_f64], [0_f64, 3_f64]];
+    assert!(
+        Orthonormal::<f64, 2, 2, Epsilons<4>>::try_new(rotation).is_ok()
+            && Orthonormal::<f64, 2, 2, Epsilons<4>>::try_new(sheared).is_err()
+            && Orthonormal::<f64, 2, 2, Epsilons<4>>::try_new(scaled).is_err()
+            && Orthonormal::<f64, 1, 2, Epsilons<4>>::try_new([[0.6_f64, 0.8_f64]]).is_ok()
+            && Symmetric::<f64, 2>::try_new(scaled).is_ok()
+            && Symmetric::<f64, 2>::try_new(rotation).is_err()
+            && Diagonal::<f64, 2, 2>::try_new(scaled).is_ok()
+            && Diagonal::<f64, 2, 2>::try_new(sheared).is_err()
+            && Diagonal::<f64, 2, 3>::try_new([[1_f64, 0_f64, 0_f64], [0_f64, 1_f64, 0_f64]])
+                .is_ok()
+            && UpperTriangular::<f64, 2, 2>::try_new(sheared).is_ok()
+            && UpperTriangular::<f64, 2, 2>::try_new(rotation).is_err()
+            && RowStochastic::<f64, 2, 2, Epsilons<4>>::try_new([
+                [0.5_f64; 2],
+                [0.25_f64, 0.75_f64]
+            ])
+            .is_ok()
+            && RowStochastic::<f64, 2, 2, Epsilons<4>>::try_new(rotation).is_err()
+    );
+    assert_eq!(
+        SymmetricInvariant::<u8, 3>::test([&[[1, 2, 3], [2, 4, 5], [3, 6, 7]]])
+            .map_err(|e| format!("{e}")),
+        Err("Element at (1, 2) is 5 but its transpose at (2, 1) is 6".into()),
+    );
+    assert_eq!(
+        UpperTriangularInvariant::<u8, 3, 3>::test([&[[1, 2, 3], [0, 4, 5], [0, 6, 7]]])
+            .map_err(|e| format!("{e}")),
+        Err("Element at (2, 1) should be zero but is 6".into()),
+    );
+}
+
+#[cfg(feature = "std")]
+#[quickcheck]
+fn normalized_vectors_have_unit_norm(x: f64, y: f64, z: f64) -> TestResult {
+    use crate::{Epsilons, Finite, UnitNorm};
+
+    let (Ok(finite_x), Ok(finite_y), Ok(finite_z)) =
+        (Finite::try_new(x), Finite::try_new(y), Finite::try_new(z))
+    else {
+        return TestResult::discard();
+    };
+    // Panics in debug builds if the result isn't within tolerance:
+    let normalized = UnitNorm::<f64, 3, Epsilons<16>>::normalize([finite_x, finite_y, finite_z]);
+    let all_zero = [x, y, z].iter().all(|&element| element == 0_f64);
+    TestResult::from_bool(normalized.is_none() == all_zero)
+}
+
+#[test]
+fn permutations_of_arrays() {
+    use crate::{Permutation, PermutationInvariant, Test as _};
+
+    let rotate = Permutation::<[usize; 4]>::new([1, 2, 3, 0]);
+    let swap = Permutation::<[usize; 4]>::new([1, 0, 2, 3]);
+    assert_eq!(rotate.inverse().get(), [3, 0, 1, 2]);
+    assert_eq!(
+        rotate.compose(&rotate.inverse()),
+        Permutation::<[usize; 4]>::identity()
+    );
+    assert_eq!(rotate.compose(&swap).get(), [0, 2, 3, 1]);
+    assert_eq!(
+        Permutation::<[usize; 5]>::argsort(&['d', 'a', 'c', 'a', 'b']).get(),
+        [1, 3, 4, 2, 0],
+    );
+    assert_eq!(
+        PermutationInvariant::<[usize; 3]>::test([&[0, 3, 1]]).map_err(|e| format!("{e}")),
+        Err("Element #1 (3) is not less than the length (3)".into()),
+    );
+    assert_eq!(
+        PermutationInvariant::<[usize; 4]>::test([&[2, 0, 3, 0]]).map_err(|e| format!("{e}")),
+        Err("Elements #1 and #3 are both 0".into()),
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[quickcheck]
+fn argsort_then_apply_sorts(values: Vec<i8>, rotation: usize) -> bool {
+    use crate::{Permutation, Sorted};
+
+    let sorting = Permutation::<Vec<usize>>::argsort(&values);
+    let sorted = sorting.apply(&values);
+    let len = values.len();
+    let rotate = Permutation::<Vec<usize>>::new(
+        (0..len)
+            .cycle()
+            .skip(rotation.checked_rem(len).unwrap_or(0))
+            .take(len)
+            .collect(),
+    );
+    rotate.compose(&sorting).apply(&values) == rotate.apply(&sorted)
+        && values.into_iter().eq(sorting.inverse().apply(&sorted))
+        && Sorted::<Vec<i8>, true>::try_new(sorted).is_ok()
+}
+
+#[test]
+fn heaps_check_each_parent_and_child() {
+    use crate::{MaxHeap, MaxHeapInvariant, MinHeap, Test as _};
+
+    let max = [9_u8, 7, 8, 1, 7, 3, 8];
+    assert!(
+        MaxHeap::try_new(max).is_ok()
+            && MinHeap::try_new(max).is_err()
+            && MinHeap::try_new([1_u8, 4, 2, 5, 4, 3]).is_ok()
+            && MaxHeap::<[u8; 0]>::try_new([]).is_ok()
+    );
+    assert_eq!(
+        MaxHeapInvariant::<[u8; 7]>::test([&[9, 7, 8, 1, 7, 3, 9]]).map_err(|e| format!("{e}")),
+        Err("Parent #2 (8) and child #6 (9) were not heap-ordered: reversed\n".into()),
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[quickcheck]
+fn heaps_agree_with_std(values: Vec<i32>) -> bool {
+    use {
+        crate::{MaxHeap, MinHeap},
+        alloc::collections::BinaryHeap,
+        core::cmp::Reverse,
+    };
+
+    let reversed: BinaryHeap<_> = values.iter().copied().map(Reverse).collect();
+    MinHeap::try_new(
+        reversed
+            .into_vec()
+            .into_iter()
+            .map(|Reverse(x)| x)
+            .collect::<Vec<_>>(),
+    )
+    .is_ok()
+        && MaxHeap::try_new(BinaryHeap::from(values).into_vec()).is_ok()
+}
+
+#[test]
+fn lengths_of_slices() {
+    use crate::{Exactly, Len, LenAtLeast, LenInRange, LenInvariant, Test as _};
+
+    let slice: &[u8] = &[1, 2, 3];
+    let exactly = Len::<&[u8], Exactly<3>>::new(slice);
+    assert_eq!(exactly.as_array(), &[1, 2, 3]);
+    assert!(
+        LenAtLeast::<&str, 4>::try_new("four").is_ok()
+            && LenAtLeast::<&str, 5>::try_new("four").is_err()
+            && LenInRange::<&[u8], 2, 3>::try_new(&[1]).is_err()
+    );
+    assert_eq!(
+        LenInvariant::<&[u8], Exactly<2>>::test([&slice]).map_err(|e| format!("{e}")),
+        Err("Length 3 is not in the range: 3 is not in 2..=2".into()),
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn bounded_collections_reject_overflow() {
+    use {
+        crate::{Exactly, Len, LenAtMost},
+        alloc::collections::VecDeque,
+    };
+
+    let mut vec = LenAtMost::<Vec<u8>, 2>::new(Vec::from([1]));
+    let mut deque = LenAtMost::<VecDeque<u8>, 2>::new(VecDeque::new());
+    let mut string = LenAtMost::<String, 3>::new("ab".into());
+    assert_eq!(
+        (
+            vec.push(2),
+            vec.push(3),
+            deque.push_back(1),
+            deque.push_front(0),
+            deque.push_back(2),
+            string.push('\u{e9}'),
+            string.push('c'),
+        ),
+        (
+            Ok(()),
+            Err(3),
+            Ok(()),
+            Ok(()),
+            Err(2),
+            Err('\u{e9}'),
+            Ok(())
+        ),
+    );
+    assert_eq!(
+        Len::<Vec<u8>, Exactly<2>>::new(vec.get()).into_array(),
+        [1, 2]
+    );
+    assert!(deque.iter().eq(&[0, 1]) && *string == "abc");
+}
+
+#[test]
+fn contextual_invariants() {
+    use crate::{InBounds, InBoundsInvariant, SigmaWith, TestWith};
+
+    struct Window {
+        end: u64,
+        start: u64,
+    }
+
+    struct InWindow;
+
+    impl TestWith<Window, u64> for InWindow {
+        const ADJECTIVE: &str = "in the configured window";
+        type Error<'i> = &'static str;
+
+        fn test_with<'i>(input: &'i u64, ctx: &'i Window) -> Result<(), Self::Error<'i>> {
+            if (ctx.start..ctx.end).contains(input) {
+                Ok(())
+            } else {
+                Err("outside the window")
+            }
+        }
+    }
+
+    let window = Window {
+        end: 2_000,
+        start: 1_000,
+    };
+    let mut timestamp = SigmaWith::<u64, InWindow, Window>::new_with(1_500, &window);
+    timestamp.map_mut_with(&window, |t| *t = t.saturating_add(499));
+    let slice = [1_u8, 2, 3];
+    assert!(
+        *timestamp == 1_999
+            && SigmaWith::<u64, InWindow, Window>::try_new_with(2_000, &window).is_err()
+            && timestamp
+                .try_check_with(&Window {
+                    end: 1_999,
+                    start: 0
+                })
+                .is_err()
+            && InBounds::try_new_with(2, &slice[..]).is_ok()
+            && InBounds::try_new_with(3, &slice[..]).is_err()
+    );
+    assert_eq!(
+        <InBoundsInvariant as TestWith<[u8], usize>>::test_with(&3, &slice)
+            .map_err(|e| format!("{e}")),
+        Err("Index 3 is out of bounds for length 3".into()),
+    );
+}
+
+#[quickcheck]
+#[cfg(debug_assertions)]
+fn in_bounds_panics_iff_out_of_bounds(index: u8, slice: Vec<u8>) -> bool {
+    use crate::InBounds;
+
+    let in_bounds = usize::from(index) < slice.len();
+    catch_unwind(move || InBounds::new_with(usize::from(index), slice.as_slice()).get()).is_ok()
+        == in_bounds
+}
+
+#[quickcheck]
+fn branded_indices_agree_with_slices(slice: Vec<u8>, index: usize) -> bool {
+    use crate::{BrandedIndex, brand};
+
+    let (agree, copied) = brand(&slice, |branded| {
+        (
+            branded.index(index).map(|i| branded.get(i)) == slice.get(index)
+                && branded.indices().map(BrandedIndex::get).eq(0..slice.len()),
+            branded
+                .indices()
+                .map(|i| *branded.get(i))
+                .collect::<Vec<_>>(),
+        )
+    });
+    agree && slice.into_iter().eq(copied)
+}
+
+#[test]
+fn try_map_family_fails_gracefully() {
+    use crate::{Negative, NonNegative, Positive, Sorted};
+
+    let three = Positive::<i32>::new(3_i32);
+    let negated: Result<Negative<i32>, _> = three.try_map(i32::saturating_neg);
+    let still_positive: Result<Positive<i32>, _> = three.try_map_ref(|x| x.saturating_neg());
+    assert!(negated.is_ok_and(|n| n.get() == -3_i32));
+    assert_eq!(
+        still_positive.map_err(|e| format!("{e}")),
+        Err("-3 is not positive: -3 <= 0".into()),
+    );
+
+    let mut sorted = Sorted::<[u8; 3], true>::new([1, 2, 3]);
+    let bumped = sorted.try_map_mut(|array| {
+        array[2] = 4;
+        array.len()
+    });
+    let rolled_back = sorted.try_map_mut(|array| array[0] = 5);
+    assert!(
+        bumped == Ok(3)
+            && rolled_back.is_err_and(|rejected| rejected.get() == [5, 2, 4])
+            && *sorted == [1, 2, 4]
+    );
+
+    let mut zero = NonNegative::<i8>::new(0);
+    assert!(zero.try_map_mut(|x| *x = -1).is_err() && zero.get() == 0);
+}
@@ -0,0 +1,685 @@
+//! Characters and strings that satisfy a given invariant
+//! (e.g. ASCII, trimmed, a valid identifier, or matching a pattern).
+//!
+//! Each invariant applies to anything that implements `Text`,
+//! e.g. `char`, `&str`, `String`, or `Box<str>`,
+//! and each error reports the byte offset of the offending character.
+
+#![expect(
+    clippy::arbitrary_source_item_ordering,
+    reason = "macros must be defined before they're used"
+)]
+
+use {
+    crate::{Sigma, Test},
+    core::{fmt, iter, marker::PhantomData, str},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+
+/// Text whose characters can be iterated (and re-iterated) alongside their byte offsets.
+pub trait Text {
+    /// Iterate over each character alongside its byte offset.
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, char)>;
+}
+
+impl Text for char {
+    #[inline]
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, Self)> {
+        iter::once((0, *self))
+    }
+}
+
+impl Text for str {
+    #[inline]
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, char)> {
+        self.char_indices()
+    }
+}
+
+impl<T: Text + ?Sized> Text for &T {
+    #[inline]
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, char)> {
+        T::char_offsets(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Text for Box<str> {
+    #[inline]
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, char)> {
+        self.char_indices()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Text for String {
+    #[inline]
+    fn char_offsets(&self) -> impl Clone + Iterator<Item = (usize, char)> {
+        self.char_indices()
+    }
+}
+
+/// Define an invariant that each character must satisfy.
+macro_rules! each_char {
+    (
+        $(#[$doc:meta])*
+        $name:ident,
+        $invariant:ident,
+        $error:ident,
+        $adjective:literal,
+        |$c:ident| $predicate:expr $(,)?
+    ) => {
+        $(#[$doc])*
+        pub type $name<Input> = Sigma<Input, $invariant<Input>>;
+
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $invariant<Input: Text + fmt::Debug>(PhantomData<Input>);
+
+        impl<Input: Text + fmt::Debug> Test<Input, 1> for $invariant<Input> {
+            const ADJECTIVE: &str = $adjective;
+
+            type Error<'i>
+                = $error
+            where
+                Input: 'i;
+
+            #[inline]
+            fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+                for (offset, $c) in input.char_offsets() {
+                    if !$predicate {
+                        return Err($error { character: $c, offset });
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        #[doc = concat!("A character was not ", $adjective, ".")]
+        #[non_exhaustive]
+        #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $error {
+            /// Offending character.
+            character: char,
+            /// Byte offset of the offending character.
+            offset: usize,
+        }
+
+        impl fmt::Display for $error {
+            #[inline]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let Self { character, offset } = *self;
+                write!(
+                    f,
+                    "{character:?} at byte offset {offset} is not {}",
+                    $adjective,
+                )
+            }
+        }
+    };
+}
+
+each_char!(
+    /// Text in which every character is ASCII.
+    Ascii,
+    AsciiInvariant,
+    NotAscii,
+    "ASCII",
+    |character| character.is_ascii(),
+);
+
+each_char!(
+    /// Text in which every character is an ASCII letter or digit.
+    AsciiAlphanumeric,
+    AsciiAlphanumericInvariant,
+    NotAsciiAlphanumeric,
+    "ASCII alphanumeric",
+    |character| character.is_ascii_alphanumeric(),
+);
+
+each_char!(
+    /// Text in which no character is uppercase
+    /// (so digits, punctuation, etc. are allowed).
+    Lowercase,
+    LowercaseInvariant,
+    NotLowercase,
+    "lowercase",
+    |character| !character.is_uppercase(),
+);
+
+each_char!(
+    /// Text in which no character is a control character (e.g. `'\n'` or `'\0'`).
+    NoControlChars,
+    NoControlCharsInvariant,
+    ControlChar,
+    "a non-control character",
+    |character| !character.is_control(),
+);
+
+/// Text that was empty.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct EmptyStr;
+
+impl fmt::Display for EmptyStr {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Empty")
+    }
+}
+
+/// Text that is a valid identifier in both Rust and C:
+/// an ASCII letter or underscore followed by any number of
+/// ASCII letters, digits, or underscores, but not a lone underscore.
+///
+/// Keywords are not rejected.
+pub type Identifier<Input> = Sigma<Input, IdentifierInvariant<Input>>;
+
+/// Text that is a valid identifier in both Rust and C:
+/// an ASCII letter or underscore followed by any number of
+/// ASCII letters, digits, or underscores, but not a lone underscore.
+///
+/// Keywords are not rejected.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct IdentifierInvariant<Input: Text + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Text + fmt::Debug> Test<Input, 1> for IdentifierInvariant<Input> {
+    const ADJECTIVE: &str = "a valid identifier";
+
+    type Error<'i>
+        = NotIdentifier
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let mut chars = input.char_offsets();
+        let Some((_, first)) = chars.next() else {
+            return Err(NotIdentifier::Empty);
+        };
+        if !(first.is_ascii_alphabetic() || first == '_') {
+            return Err(NotIdentifier::InvalidChar {
+                character: first,
+                offset: 0,
+            });
+        }
+        let mut lone = true;
+        for (offset, character) in chars {
+            if !(character.is_ascii_alphanumeric() || character == '_') {
+                return Err(NotIdentifier::InvalidChar { character, offset });
+            }
+            lone = false;
+        }
+        if lone && first == '_' {
+            return Err(NotIdentifier::LoneUnderscore);
+        }
+        Ok(())
+    }
+}
+
+/// Text that was not a valid identifier.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum NotIdentifier {
+    /// Empty text.
+    Empty,
+    /// A character that can't appear at its position in an identifier.
+    InvalidChar {
+        /// Offending character.
+        character: char,
+        /// Byte offset of the offending character.
+        offset: usize,
+    },
+    /// A lone underscore, which is a pattern (not an identifier) in Rust.
+    LoneUnderscore,
+}
+
+impl fmt::Display for NotIdentifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        match *self {
+            Self::Empty => write!(f, "Empty"),
+            Self::InvalidChar { character, offset } => write!(
+                f,
+                "{character:?} at byte offset {offset} can't appear there in an identifier",
+            ),
+            Self::LoneUnderscore => write!(f, "A lone underscore is not an identifier"),
+        }
+    }
+}
+
+/// Text at most `N` bytes long (when encoded as UTF-8).
+pub type MaxLen<Input, const N: usize> = Sigma<Input, MaxLenInvariant<Input, N>>;
+
+/// Text at most `N` bytes long (when encoded as UTF-8).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MaxLenInvariant<Input: Text + fmt::Debug, const N: usize>(PhantomData<Input>);
+
+impl<Input: Text + fmt::Debug, const N: usize> Test<Input, 1> for MaxLenInvariant<Input, N> {
+    const ADJECTIVE: &str = "short enough";
+
+    type Error<'i>
+        = TooLong<N>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        for (offset, character) in input.char_offsets() {
+            if offset.saturating_add(character.len_utf8()) > N {
+                return Err(TooLong { character, offset });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Nonempty text.
+pub type NonEmptyStr<Input> = Sigma<Input, NonEmptyStrInvariant<Input>>;
+
+/// Nonempty text.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonEmptyStrInvariant<Input: Text + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Text + fmt::Debug> Test<Input, 1> for NonEmptyStrInvariant<Input> {
+    const ADJECTIVE: &str = "nonempty";
+
+    type Error<'i>
+        = EmptyStr
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        if input.char_offsets().next().is_some() {
+            Ok(())
+        } else {
+            Err(EmptyStr)
+        }
+    }
+}
+
+/// Text with leading or trailing whitespace.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotTrimmed {
+    /// Offending whitespace character.
+    character: char,
+    /// Byte offset of the offending whitespace character.
+    offset: usize,
+}
+
+impl fmt::Display for NotTrimmed {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { character, offset } = *self;
+        write!(
+            f,
+            "{character:?} at byte offset {offset} is leading or trailing whitespace",
+        )
+    }
+}
+
+/// Text that was longer than `N` bytes (when encoded as UTF-8).
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TooLong<const N: usize> {
+    /// First character that ends past the limit.
+    character: char,
+    /// Byte offset of the first character that ends past the limit.
+    offset: usize,
+}
+
+impl<const N: usize> fmt::Display for TooLong<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { character, offset } = *self;
+        write!(
+            f,
+            "{character:?} at byte offset {offset} ends past the limit of {N} bytes",
+        )
+    }
+}
+
+/// Text without leading or trailing whitespace.
+pub type Trimmed<Input> = Sigma<Input, TrimmedInvariant<Input>>;
+
+/// Text without leading or trailing whitespace.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TrimmedInvariant<Input: Text + fmt::Debug>(PhantomData<Input>);
+
+impl<Input: Text + fmt::Debug> Test<Input, 1> for TrimmedInvariant<Input> {
+    const ADJECTIVE: &str = "trimmed";
+
+    type Error<'i>
+        = NotTrimmed
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        let mut chars = input.char_offsets();
+        let Some(first) = chars.next() else {
+            return Ok(());
+        };
+        let last = chars.last().unwrap_or(first);
+        for (offset, character) in [first, last] {
+            if character.is_whitespace() {
+                return Err(NotTrimmed { character, offset });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Zero-sized type standing for a pattern that text must match in its entirety.
+///
+/// The grammar is a small mix of globs and character classes:
+/// - `?` matches any single character;
+/// - `[abc]`, `[a-z]`, and `[!a-z]` match any single character in
+///   (or, with `!`, not in) a set of characters and inclusive ranges;
+/// - `\` escapes the following character (inside or outside a class);
+/// - a postfix `*` or `+` matches the preceding item zero-or-more or one-or-more times
+///   (so a glob's `*` is written `?*`);
+/// - any other character matches itself.
+///
+/// Patterns are checked at compile time, and matching neither allocates nor needs `std`.
+/// Matching never backtracks, so it takes time proportional to
+/// the length of the input times the length of the pattern.
+pub trait Pattern {
+    /// Text of the pattern.
+    const PATTERN: &'static str;
+}
+
+/// Text that matches a pattern in its entirety.
+pub type Matches<Input, P> = Sigma<Input, MatchesInvariant<Input, P>>;
+
+/// Text that matches a pattern in its entirety.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct MatchesInvariant<Input: Text + fmt::Debug, P: Pattern>(
+    PhantomData<Input>,
+    PhantomData<P>,
+);
+
+impl<Input: Text + fmt::Debug, P: Pattern> Test<Input, 1> for MatchesInvariant<Input, P> {
+    const ADJECTIVE: &str = "matching the pattern";
+
+    type Error<'i>
+        = Mismatch
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        const { assert!(valid_pattern(P::PATTERN), "invalid pattern") };
+        // Instead of backtracking, track every item the input so far could have reached
+        // (bit `i` of `before` if it could be about to match item `i`,
+        // and bit `i` of `during` if it could be partway through repeating item `i`),
+        // which takes time proportional to the length of the input times the length of the pattern.
+
+        // Move past every item that could already be done
+        // (either repeated zero times or partway through repeating):
+        let skip = |mut before: u64, during: u64| {
+            for (i, (_, repeat)) in items(P::PATTERN).enumerate() {
+                let bit = 1 << i;
+                if during & bit != 0 || (before & bit != 0 && repeat == Repeat::ZeroOrMore) {
+                    before |= bit << 1_u32;
+                }
+            }
+            before
+        };
+
+        let mut before = skip(1, 0);
+        let mut during = 0;
+        let mut end = 0;
+        for (offset, character) in input.char_offsets() {
+            let mut next_before = 0;
+            let mut next_during = 0;
+            for (i, (atom, repeat)) in items(P::PATTERN).enumerate() {
+                let bit = 1 << i;
+                if !atom.matches(character) {
+                    continue;
+                }
+                if before & bit != 0 {
+                    if repeat == Repeat::Once {
+                        next_before |= bit << 1_u32;
+                    } else {
+                        next_during |= bit;
+                    }
+                }
+                if during & bit != 0 {
+                    next_during |= bit;
+                }
+            }
+            during = next_during;
+            before = skip(next_before, during);
+            if before == 0 {
+                return Err(Mismatch {
+                    offset,
+                    pattern: P::PATTERN,
+                });
+            }
+            end = offset.saturating_add(character.len_utf8());
+        }
+        if before & (1 << items(P::PATTERN).count()) == 0 {
+            Err(Mismatch {
+                offset: end,
+                pattern: P::PATTERN,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Text that did not match a pattern.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Mismatch {
+    /// Furthest byte offset that any attempted match reached before diverging.
+    offset: usize,
+    /// Text of the pattern.
+    pattern: &'static str,
+}
+
+impl fmt::Display for Mismatch {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self { offset, pattern } = *self;
+        write!(
+            f,
+            "Diverged from the pattern {pattern:?} at byte offset {offset}"
+        )
+    }
+}
+
+/// Single item of a pattern, matching exactly one character.
+#[derive(Clone, Copy, Debug)]
+enum Atom<'p> {
+    /// Any character.
+    Any,
+    /// Any character in (or, if negated, not in) a set.
+    Class {
+        /// Contents between the brackets (excluding any leading `!`).
+        body: &'p str,
+        /// Whether a leading `!` inverted this set.
+        negated: bool,
+    },
+    /// Exactly this character.
+    Literal(char),
+}
+
+impl Atom<'_> {
+    /// Check whether this atom matches a character.
+    #[inline]
+    fn matches(self, character: char) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Class { body, negated } => {
+                let mut chars = body.chars();
+                while let Some(lo) = unescape(&mut chars) {
+                    let hi = match chars.as_str().strip_prefix('-') {
+                        Some(after_dash) if !after_dash.is_empty() => {
+                            chars = after_dash.chars();
+                            let Some(hi) = unescape(&mut chars) else {
+                                return negated;
+                            };
+                            hi
+                        }
+                        _ => lo,
+                    };
+                    if (lo..=hi).contains(&character) {
+                        return !negated;
+                    }
+                }
+                negated
+            }
+            Self::Literal(literal) => literal == character,
+        }
+    }
+}
+
+/// How many times an atom may repeat.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Repeat {
+    /// Exactly once.
+    Once,
+    /// One or more times (`+`).
+    OneOrMore,
+    /// Zero or more times (`*`).
+    ZeroOrMore,
+}
+
+/// Every item (an atom and how many times it may repeat) of a pattern, in order.
+#[inline]
+fn items(pattern: &str) -> impl Iterator<Item = (Atom<'_>, Repeat)> {
+    let mut chars = pattern.chars();
+    iter::from_fn(move || {
+        let atom = match chars.next()? {
+            '\\' => chars.next().map_or(Atom::Any, Atom::Literal),
+            '?' => Atom::Any,
+            '[' => {
+                let after_bracket = chars.as_str();
+                let (negated, class) = after_bracket
+                    .strip_prefix('!')
+                    .map_or((false, after_bracket), |after_bang| (true, after_bang));
+                let mut escaped = false;
+                let end = class
+                    .char_indices()
+                    .find(|&(_, character)| {
+                        let close = !escaped && character == ']';
+                        escaped = !escaped && character == '\\';
+                        close
+                    })
+                    .map_or(class.len(), |(end, _)| end);
+                let (body, after_class) = class.split_at(end);
+                chars = after_class.get(1..).unwrap_or_default().chars();
+                Atom::Class { body, negated }
+            }
+            literal => Atom::Literal(literal),
+        };
+        let mut after_atom = chars.clone();
+        let repeat = match after_atom.next() {
+            Some('*') => Repeat::ZeroOrMore,
+            Some('+') => Repeat::OneOrMore,
+            _ => return Some((atom, Repeat::Once)),
+        };
+        chars = after_atom;
+        Some((atom, repeat))
+    })
+}
+
+/// Take the next character of a class, following a `\` if necessary.
+#[inline]
+fn unescape(chars: &mut str::Chars<'_>) -> Option<char> {
+    match chars.next()? {
+        '\\' => chars.next(),
+        character => Some(character),
+    }
+}
+
+/// Check that a pattern is well-formed.
+///
+/// Every class must be closed and nonempty, every `\` must escape something,
+/// every `*` or `+` must follow an atom, and there can be at most 63 atoms
+/// (since matching tracks each in one bit of a `u64`).
+///
+/// `Matches` already checks this at compile time for every `Pattern` it uses.
+#[inline]
+#[must_use]
+pub const fn valid_pattern(pattern: &str) -> bool {
+    let mut bytes = pattern.as_bytes();
+    // Whether the last item could take a `*` or `+`:
+    let mut atom = false;
+    let mut atoms: u32 = 0;
+    loop {
+        match *bytes {
+            [] => return atoms < u64::BITS,
+            [b'\\'] => return false,
+            [b'*' | b'+', ref after_quantifier @ ..] => {
+                if !atom {
+                    return false;
+                }
+                bytes = after_quantifier;
+                atom = false;
+            }
+            [b'[', ref after_bracket @ ..] => {
+                bytes = match *after_bracket {
+                    [b'!', ref after_bang @ ..] => after_bang,
+                    _ => after_bracket,
+                };
+                // The first character can't close the class, since classes can't be empty:
+                if let [] | [b']', ..] = *bytes {
+                    return false;
+                }
+                loop {
+                    match *bytes {
+                        [] | [b'\\'] => return false,
+                        [b']', ref after_class @ ..] => {
+                            bytes = after_class;
+                            break;
+                        }
+                        [b'\\', _, ref after @ ..] | [_, ref after @ ..] => bytes = after,
+                    }
+                }
+                atom = true;
+                atoms = atoms.saturating_add(1);
+            }
+            [b'\\', _, ref after @ ..] => {
+                bytes = after;
+                atom = true;
+                atoms = atoms.saturating_add(1);
+            }
+            [lead, ref after @ ..] => {
+                bytes = after;
+                atom = true;
+                // Count each character once, not each of its UTF-8 continuation bytes:
+                if lead & 0b1100_0000 != 0b1000_0000 {
+                    atoms = atoms.saturating_add(1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Iterable data structure in which no two elements are equal.
+
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, btree_map};
+
+#[cfg(feature = "std")]
+use {
+    core::hash::Hash,
+    std::collections::{HashMap, hash_map},
+};
+
+/// Compare every element against every element before it (via `Eq`).
+/// Takes quadratic time but needs neither `alloc` nor any bound beyond `Eq`.
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByEq;
+
+/// Remember each element in a `HashMap` (via `Hash` and `Eq`).
+/// Takes expected linear time.
+#[cfg(feature = "std")]
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByHash;
+
+/// Remember each element in a `BTreeMap` (via `Ord`).
+/// Takes linearithmic time.
+#[cfg(feature = "alloc")]
+#[expect(clippy::exhaustive_structs, reason = "no fields")]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ByOrd;
+
+/// Strategy to find the first pair of equal elements in an iterable data structure.
+///
+/// Every strategy must agree on which pair is "first":
+/// the pair whose *second* element appears earliest,
+/// paired with the earliest element equal to it.
+pub trait FindDuplicate<Input>
+where
+    Input: IntoIterator,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    /// Find the first pair of equal elements, if any.
+    fn find_duplicate(input: &Input) -> Option<Duplicate<'_, Input::Item>>;
+}
+
+/// Pair of equal elements, each with its index:
+/// `((index_of_fst, elem_fst), (index_of_snd, elem_snd))`.
+pub type Duplicate<'i, Item> = ((usize, &'i Item), (usize, &'i Item));
+
+impl<Input: IntoIterator> FindDuplicate<Input> for ByEq
+where
+    Input::Item: Eq,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    #[inline]
+    fn find_duplicate(input: &Input) -> Option<Duplicate<'_, Input::Item>> {
+        for (index_of_snd, elem_snd) in input.into_iter().enumerate() {
+            for (index_of_fst, elem_fst) in input.into_iter().take(index_of_snd).enumerate() {
+                if elem_fst == elem_snd {
+                    return Some(((index_of_fst, elem_fst), (index_of_snd, elem_snd)));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Input: IntoIterator> FindDuplicate<Input> for ByHash
+where
+    Input::Item: Eq + Hash,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    #[inline]
+    fn find_duplicate(input: &Input) -> Option<Duplicate<'_, Input::Item>> {
+        let mut seen = HashMap::new();
+        for (index_of_snd, elem_snd) in input.into_iter().enumerate() {
+            match seen.entry(elem_snd) {
+                hash_map::Entry::Occupied(occupied) => {
+                    let (&elem_fst, &index_of_fst) = (occupied.key(), occupied.get());
+                    return Some(((index_of_fst, elem_fst), (index_of_snd, elem_snd)));
+                }
+                hash_map::Entry::Vacant(vacant) => {
+                    vacant.insert(index_of_snd);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Input: IntoIterator> FindDuplicate<Input> for ByOrd
+where
+    Input::Item: Ord,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    #[inline]
+    fn find_duplicate(input: &Input) -> Option<Duplicate<'_, Input::Item>> {
+        let mut seen = BTreeMap::new();
+        for (index_of_snd, elem_snd) in input.into_iter().enumerate() {
+            match seen.entry(elem_snd) {
+                btree_map::Entry::Occupied(occupied) => {
+                    let (&elem_fst, &index_of_fst) = (occupied.key(), occupied.get());
+                    return Some(((index_of_fst, elem_fst), (index_of_snd, elem_snd)));
+                }
+                btree_map::Entry::Vacant(vacant) => {
+                    vacant.insert(index_of_snd);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Two elements in a supposedly unique iterator were equal.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NotUnique<'i, Item: fmt::Debug> {
+    /// First element of the pair.
+    elem_fst: &'i Item,
+    /// Second element of the pair.
+    elem_snd: &'i Item,
+    /// After how many other elements
+    /// did we see the first element of this pair?
+    index_of_fst: usize,
+    /// After how many other elements
+    /// did we see the second element of this pair?
+    index_of_snd: usize,
+}
+
+impl<Item: fmt::Debug> fmt::Display for NotUnique<'_, Item> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #![expect(
+            clippy::use_debug,
+            reason = "Intentional and informative, not just forgotten print-debugging"
+        )]
+
+        let Self {
+            elem_fst,
+            elem_snd,
+            index_of_fst,
+            index_of_snd,
+        } = *self;
+        write!(
+            f,
+            "Elements #{index_of_fst} and #{index_of_snd} ({elem_fst:#?} and {elem_snd:#?}) were equal",
+        )
+    }
+}
+
+/// Iterable data structure in which no two elements are equal.
+pub type Unique<Input, Strategy = ByEq> = crate::Sigma<Input, UniqueInvariant<Input, Strategy>>;
+
+/// Iterable data structure in which no two elements are equal.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UniqueInvariant<Input: IntoIterator + fmt::Debug, Strategy: FindDuplicate<Input> = ByEq>(
+    PhantomData<Input>,
+    PhantomData<Strategy>,
+)
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>;
+
+impl<Input: IntoIterator + fmt::Debug, Strategy: FindDuplicate<Input>> crate::Test<Input, 1>
+    for UniqueInvariant<Input, Strategy>
+where
+    Input::Item: fmt::Debug,
+    for<'i> &'i Input: IntoIterator<Item = &'i Input::Item>,
+{
+    const ADJECTIVE: &str = "unique";
+
+    type Error<'i>
+        = NotUnique<'i, Input::Item>
+    where
+        Input: 'i;
+
+    #[inline]
+    fn test([input]: [&Input; 1]) -> Result<(), Self::Error<'_>> {
+        match Strategy::find_duplicate(input) {
+            None => Ok(()),
+            Some(((index_of_fst, elem_fst), (index_of_snd, elem_snd))) => Err(NotUnique {
+                elem_fst,
+                elem_snd,
+                index_of_fst,
+                index_of_snd,
+            }),
+        }
+    }
+}